@@ -0,0 +1,86 @@
+use rowan::TextRange;
+use std::fmt;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// moc's code for syntax errors
+pub const SYNTAX_ERROR: &str = "M0001";
+
+/// A secondary location that helps explain a diagnostic, like the opening
+/// paren for an unclosed paren.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Label {
+    pub range: TextRange,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// moc's error code, warnings about deprecated syntax don't have one
+    pub code: Option<&'static str>,
+    pub range: TextRange,
+    pub message: String,
+    pub labels: Vec<Label>,
+}
+
+impl Diagnostic {
+    pub fn error(code: &'static str, range: TextRange, message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            code: Some(code),
+            range,
+            message: message.into(),
+            labels: vec![],
+        }
+    }
+
+    pub fn warning(range: TextRange, message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity: Severity::Warning,
+            code: None,
+            range,
+            message: message.into(),
+            labels: vec![],
+        }
+    }
+
+    pub fn with_label(mut self, range: TextRange, message: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            range,
+            message: message.into(),
+        });
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.severity)?;
+        if let Some(code) = self.code {
+            write!(f, "[{}]", code)?;
+        }
+        write!(f, "@{:?}: {}", self.range, self.message)?;
+        for label in &self.labels {
+            write!(f, "\n  {:?}: {}", label.range, label.message)?;
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
+mod syntax_kind;
+
 use logos::Logos;
-use num_derive::{FromPrimitive, ToPrimitive};
+pub use syntax_kind::SyntaxKind;
 
 pub(crate) type Token<'a> = (
     Vec<(SyntaxKind, &'a str)>,
@@ -22,10 +24,15 @@ impl<'a> Lexer<'a> {
 
     pub(crate) fn lex(input: &'a str) -> Vec<Token<'a>> {
         let mut lexer = Lexer::new(input);
-        let mut tokens = vec![];
+        let mut tokens: Vec<Token> = vec![];
         loop {
-            let tkn = lexer.next().unwrap();
+            let mut tkn = lexer.next().unwrap();
             if tkn.1 .0 == SyntaxKind::EOF {
+                // Trivia at the end of the input belongs to the last token, so
+                // it ends up in the tree
+                if let Some((_, _, trailing)) = tokens.last_mut() {
+                    trailing.append(&mut tkn.0);
+                }
                 tokens.push(tkn);
                 return tokens;
             }
@@ -45,10 +52,39 @@ fn is_whitespace(kind: SyntaxKind) -> bool {
             | SyntaxKind::TAB
             | SyntaxKind::LINEFEED
             | SyntaxKind::LINE_COMMENT
+            | SyntaxKind::LINE_DOC_COMMENT
             | SyntaxKind::BLOCK_COMMENT
+            | SyntaxKind::BLOCK_DOC_COMMENT
     )
 }
 
+/// Block comments nest, so `/* /* */ */` is a single comment. Consumes the
+/// rest of the comment after its opening `/*`. An unterminated comment
+/// swallows the rest of the input and becomes an `ERROR`.
+fn block_comment(lex: &mut logos::Lexer<SyntaxKind>) -> bool {
+    let start = lex.span().start + 2;
+    let rest = &lex.source().as_bytes()[start..];
+    let mut depth = 1;
+    let mut i = 0;
+    while i < rest.len() {
+        if rest[i..].starts_with(b"/*") {
+            depth += 1;
+            i += 2;
+        } else if rest[i..].starts_with(b"*/") {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+                lex.bump(start + i - lex.span().end);
+                return true;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    lex.bump(start + rest.len() - lex.span().end);
+    false
+}
+
 impl<'a> Lexer<'a> {
     fn inner_peek(&mut self) -> Option<(SyntaxKind, &'a str)> {
         match self.lookahead {
@@ -98,269 +134,8 @@ impl<'a> Iterator for Lexer<'a> {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Logos, FromPrimitive, ToPrimitive)]
-#[repr(u16)]
-#[allow(bad_style, missing_docs, unreachable_pub)]
-pub enum SyntaxKind {
-    #[regex(" +")]
-    SPACE,
-
-    #[regex(r"//[^\r\n]*")]
-    LINE_COMMENT,
-
-    #[regex(r"/\*(?:[^*]|\*[^/])*\*/")]
-    BLOCK_COMMENT,
-
-    #[regex("\t+")]
-    TAB,
-
-    #[regex("\n|\r\n")]
-    LINEFEED,
-
-    #[token("func")]
-    FUNC_KW,
-
-    #[token("let")]
-    LET_KW,
-
-    #[token("var")]
-    VAR_KW,
-
-    #[token("switch")]
-    SWITCH_KW,
-
-    #[token("case")]
-    CASE_KW,
-
-    #[token("true")]
-    TRUE_KW,
-
-    #[token("false")]
-    FALSE_KW,
-
-    #[token("null")]
-    NULL_KW,
-
-    #[token("shared")]
-    SHARED_KW,
-
-    #[token("query")]
-    QUERY_KW,
-
-    #[token("async")]
-    ASYNC_KW,
-
-    #[token("object")]
-    OBJECT_KW,
-
-    #[token("class")]
-    CLASS_KW,
-
-    #[token("actor")]
-    ACTOR_KW,
-
-    #[token("module")]
-    MODULE_KW,
-
-    #[token("prim")]
-    PRIM_KW,
-
-    #[regex("[A-Za-z][A-Za-z0-9]*")]
-    IDENT,
-
-    #[regex("[0-9]+")]
-    NUMBER_LIT,
-
-    #[token(".")]
-    DOT,
-
-    #[token("+")]
-    PLUS,
-
-    #[token("-")]
-    MINUS,
-
-    #[token("_")]
-    UNDERSCORE,
-
-    #[token("*")]
-    STAR,
-
-    #[token("/")]
-    SLASH,
-
-    #[token("=")]
-    EQUALS,
-
-    #[token("==")]
-    DOUBLE_EQUALS,
-
-    #[token("(")]
-    L_PAREN,
-
-    #[token(")")]
-    R_PAREN,
-
-    #[token("[")]
-    L_BRACKET,
-
-    #[token("]")]
-    R_BRACKET,
-
-    #[token("{")]
-    L_BRACE,
-
-    #[token("}")]
-    R_BRACE,
-
-    #[token("<")]
-    L_ANGLE,
-
-    #[token(">")]
-    R_ANGLE,
-
-    #[token("<:")]
-    SUB,
-
-    #[token("#")]
-    HASH,
-
-    #[token("?")]
-    QUESTION,
-
-    #[token("!")]
-    BANG,
-
-    #[token(":")]
-    COLON,
-
-    #[token(";")]
-    SEMICOLON,
-
-    #[token(",")]
-    COMMA,
-
-    #[token("->")]
-    ARROW,
-
-    #[error]
-    ERROR,
-
-    EOF,
-
-    // Composite nodes
-
-    // Types
-    OPTIONAL_TYPE,
-    PAREN_TYPE,
-    ASYNC_TYPE,
-    PATH_TYPE,
-    ARRAY_TYPE,
-    FUNC_TYPE,
-    OBJECT_TYPE,
-    VARIANT_TYPE,
-    TUPLE_TYPE,
-    NAMED_TYPE,
-    PRIM_TYPE,
-
-    // Expressions
-    Parenthesize, // a parenthesized expression
-    LiteralE,     // a literal
-    VarE,         // wraps a WORD token
-    FuncE,        // a func expression
-    ApplicationE, // a function application
-    BlockE,       // A block expression
-
-    // Declarations
-    LetD, // A let-declaration
-    VarD, // A variable declaration
-    Root,
-
-    // Patterns
-    VAR_PAT,
-    WILDCARD_PAT,
-    LITERAL_PAT,
-    PAREN_PAT,
-    TUPLE_PAT,
-    OBJECT_PAT,
-    VARIANT_PAT,
-
-    // Literals
-    LITERAL,
-
-    // Modifiers
-    PATH,
-    NAME,
-    TAG,
-    MutModifier,
-    TYPE_ARGS,
-    TYPE_PARAMS,
-    TYPE_BIND,
-    TYPE_BOUND,
-    TYPE_TAG,
-    TYPE_FIELD,
-    TYPE_FIELD_FUNC,
-    TYPE_ANNOTATION,
-    FUNC_ARG,
-    FUNC_RESULT,
-    FUNC_SORT,
-    PATTERN_FIELD_PUN,
-    PATTERN_FIELD_PAT,
-    PATTERN_VARIANT_PAT,
-    OBJECT_SORT,
-}
-
-#[macro_export]
-macro_rules ! T {
-    [;] => { SyntaxKind::SEMICOLON };
-    [,] => { SyntaxKind::COMMA };
-    ['('] => { SyntaxKind::L_PAREN };
-    [')'] => { SyntaxKind::R_PAREN };
-    ['{'] => { SyntaxKind::L_BRACE };
-    ['}'] => { SyntaxKind::R_BRACE };
-    ['['] => { SyntaxKind::L_BRACKET };
-    [']'] => { SyntaxKind::R_BRACKET };
-    [<] => { SyntaxKind::L_ANGLE };
-    [>] => { SyntaxKind::R_ANGLE };
-    [#] => { SyntaxKind::HASH };
-    [?] => { SyntaxKind::QUESTION };
-    [+] => { SyntaxKind::PLUS };
-    [*] => { SyntaxKind::STAR };
-    [/] => { SyntaxKind::SLASH };
-    [^] => { SyntaxKind::CARET };
-    [%] => { SyntaxKind::PERCENT };
-    [_] => { SyntaxKind::UNDERSCORE };
-    [.] => { SyntaxKind::DOT };
-    [:] => { SyntaxKind::COLON };
-    [=] => { SyntaxKind::EQUALS };
-    [==] => { SyntaxKind::DOUBLE_EQUALS };
-    [!] => { SyntaxKind::BANG };
-    [-] => { SyntaxKind::MINUS };
-    [->] => { SyntaxKind::ARROW };
-    [<:] => { SyntaxKind::SUB };
-    [actor] => { SyntaxKind::ACTOR_KW };
-    [class] => { SyntaxKind::CLASS_KW };
-    [object] => { SyntaxKind::OBJECT_KW };
-    [async] => { SyntaxKind::ASYNC_KW };
-    [true] => { SyntaxKind::TRUE_KW };
-    [false] => { SyntaxKind::FALSE_KW };
-    [null] => { SyntaxKind::NULL_KW };
-    [func] => { SyntaxKind::FUNC_KW };
-    [for] => { SyntaxKind::FOR_KW };
-    [if] => { SyntaxKind::IF_KW };
-    [else] => { SyntaxKind::ELSE_KW };
-    [let] => { SyntaxKind::LET_KW };
-    [switch] => { SyntaxKind::SWITCH_KW };
-    [module] => { SyntaxKind::MODULE_KW };
-    [var] => { SyntaxKind::VAR_KW };
-    [prim] => { SyntaxKind::PRIM_KW };
-    [query] => { SyntaxKind::QUERY_KW };
-    [shared] => { SyntaxKind::SHARED_KW };
-    [return] => { SyntaxKind::RETURN_KW };
-    [true] => { SyntaxKind::TRUE_KW };
-    [try] => { SyntaxKind::TRY_KW };
-    [type] => { SyntaxKind::TYPE_KW };
-    [while] => { SyntaxKind::WHILE_KW };
-    [number_lit] => { SyntaxKind::NUMBER_LIT };
-    [ident] => { SyntaxKind::IDENT };
+impl SyntaxKind {
+    pub fn is_trivia(self) -> bool {
+        is_whitespace(self)
+    }
 }
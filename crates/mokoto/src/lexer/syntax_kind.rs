@@ -0,0 +1,576 @@
+//! Generated by `cargo xtask codegen`, do not edit by hand.
+
+use super::block_comment;
+use logos::Logos;
+use num_derive::{FromPrimitive, ToPrimitive};
+#[derive(Debug, Copy, Clone, PartialEq, Logos, FromPrimitive, ToPrimitive)]
+#[repr(u16)]
+#[allow(bad_style, missing_docs, unreachable_pub)]
+pub enum SyntaxKind {
+    #[regex(" +")]
+    SPACE,
+    #[regex(r"//[^\r\n]*")]
+    #[regex(r"////[^\r\n]*", priority = 7)]
+    LINE_COMMENT,
+    #[regex(r"///[^\r\n]*")]
+    LINE_DOC_COMMENT,
+    /// `/**/` and `/***` are ordinary comments, like in Rust
+    #[token("/*", block_comment)]
+    #[token("/**/", block_comment)]
+    #[token("/***", block_comment)]
+    BLOCK_COMMENT,
+    #[token("/**", block_comment)]
+    BLOCK_DOC_COMMENT,
+    #[regex("\t+")]
+    TAB,
+    #[regex("\n|\r\n")]
+    LINEFEED,
+    #[token("func")]
+    FUNC_KW,
+    #[token("let")]
+    LET_KW,
+    #[token("var")]
+    VAR_KW,
+    #[token("switch")]
+    SWITCH_KW,
+    #[token("case")]
+    CASE_KW,
+    #[token("true")]
+    TRUE_KW,
+    #[token("false")]
+    FALSE_KW,
+    #[token("null")]
+    NULL_KW,
+    #[token("shared")]
+    SHARED_KW,
+    #[token("query")]
+    QUERY_KW,
+    #[token("async")]
+    ASYNC_KW,
+    #[token("async*")]
+    ASYNC_STAR_KW,
+    #[token("object")]
+    OBJECT_KW,
+    #[token("class")]
+    CLASS_KW,
+    #[token("actor")]
+    ACTOR_KW,
+    #[token("module")]
+    MODULE_KW,
+    #[token("prim")]
+    PRIM_KW,
+    #[token("if")]
+    IF_KW,
+    #[token("else")]
+    ELSE_KW,
+    #[token("while")]
+    WHILE_KW,
+    #[token("loop")]
+    LOOP_KW,
+    #[token("for")]
+    FOR_KW,
+    #[token("in")]
+    IN_KW,
+    #[token("return")]
+    RETURN_KW,
+    #[token("await")]
+    AWAIT_KW,
+    #[token("await*")]
+    AWAIT_STAR_KW,
+    #[token("assert")]
+    ASSERT_KW,
+    #[token("debug")]
+    DEBUG_KW,
+    #[token("debug_show")]
+    DEBUG_SHOW_KW,
+    #[token("ignore")]
+    IGNORE_KW,
+    #[token("and")]
+    AND_KW,
+    #[token("or")]
+    OR_KW,
+    #[token("not")]
+    NOT_KW,
+    #[token("label")]
+    LABEL_KW,
+    #[token("break")]
+    BREAK_KW,
+    #[token("continue")]
+    CONTINUE_KW,
+    #[token("try")]
+    TRY_KW,
+    #[token("catch")]
+    CATCH_KW,
+    #[token("throw")]
+    THROW_KW,
+    #[token("do")]
+    DO_KW,
+    #[token("with")]
+    WITH_KW,
+    #[token("import")]
+    IMPORT_KW,
+    #[token("public")]
+    PUBLIC_KW,
+    #[token("private")]
+    PRIVATE_KW,
+    #[token("system")]
+    SYSTEM_KW,
+    #[token("stable")]
+    STABLE_KW,
+    #[token("flexible")]
+    FLEXIBLE_KW,
+    #[token("type")]
+    TYPE_KW,
+    /// Like moc, identifiers are ASCII only. Any other character is lexed as an `ERROR` spanning the whole code point.
+    #[regex("[A-Za-z_][A-Za-z0-9_]*")]
+    IDENT,
+    /// Tuple projections like the `.1` in `t.1`
+    #[regex(r"\.[0-9][0-9_]*")]
+    DOT_NUM,
+    /// Misplaced underscores and missing digits are reported when the literal is decoded, see `syntax::number`
+    #[regex("[0-9][0-9_]*")]
+    #[regex("0x[0-9a-fA-F_]*")]
+    NAT_LIT,
+    #[regex(r"[0-9][0-9_]*\.[0-9_]*")]
+    #[regex(r"[0-9][0-9_]*(\.[0-9_]*)?[eE][+-]?[0-9][0-9_]*")]
+    #[regex(r"0x[0-9a-fA-F_]*\.[0-9a-fA-F_]*")]
+    #[regex(r"0x[0-9a-fA-F_]*(\.[0-9a-fA-F_]*)?[pP][+-]?[0-9][0-9_]*")]
+    FLOAT_LIT,
+    /// Escapes are validated when the literal is decoded, see `syntax::unescape`
+    #[regex(r#""(?:[^"\\]|\\(?:.|\n))*""#)]
+    TEXT_LIT,
+    #[regex(r"'(?:[^'\\\n]|\\.)*'")]
+    CHAR_LIT,
+    #[token(".")]
+    DOT,
+    #[token("+")]
+    PLUS,
+    #[token("-")]
+    MINUS,
+    #[token("_")]
+    UNDERSCORE,
+    #[token("*")]
+    STAR,
+    #[token("/")]
+    SLASH,
+    #[token("=")]
+    EQUALS,
+    #[token("==")]
+    DOUBLE_EQUALS,
+    #[token("(")]
+    L_PAREN,
+    #[token(")")]
+    R_PAREN,
+    #[token("[")]
+    L_BRACKET,
+    #[token("]")]
+    R_BRACKET,
+    #[token("{")]
+    L_BRACE,
+    #[token("}")]
+    R_BRACE,
+    #[token("<")]
+    L_ANGLE,
+    #[token(">")]
+    R_ANGLE,
+    #[token("<:")]
+    SUB,
+    #[token("#")]
+    HASH,
+    #[token("?")]
+    QUESTION,
+    #[token("!")]
+    BANG,
+    #[token(":")]
+    COLON,
+    #[token(";")]
+    SEMICOLON,
+    #[token(",")]
+    COMMA,
+    #[token("->")]
+    ARROW,
+    #[token("!=")]
+    NOT_EQUALS,
+    #[token("%")]
+    PERCENT,
+    #[token("**")]
+    DOUBLE_STAR,
+    #[token("&")]
+    AMPERSAND,
+    #[token("|")]
+    PIPE,
+    #[token("^")]
+    CARET,
+    #[token("|>")]
+    PIPE_GREATER,
+    #[token(":=")]
+    COLON_EQUALS,
+    #[token("+=")]
+    PLUS_EQUALS,
+    #[token("-=")]
+    MINUS_EQUALS,
+    #[token("*=")]
+    STAR_EQUALS,
+    #[token("/=")]
+    SLASH_EQUALS,
+    #[token("%=")]
+    PERCENT_EQUALS,
+    #[token("**=")]
+    DOUBLE_STAR_EQUALS,
+    #[token("&=")]
+    AMPERSAND_EQUALS,
+    #[token("|=")]
+    PIPE_EQUALS,
+    #[token("^=")]
+    CARET_EQUALS,
+    #[token("#=")]
+    HASH_EQUALS,
+    LESS_EQUALS,
+    GREATER_EQUALS,
+    SHL,
+    USHR,
+    SSHR,
+    ROTL,
+    ROTR,
+    SHL_EQUALS,
+    USHR_EQUALS,
+    SSHR_EQUALS,
+    ROTL_EQUALS,
+    ROTR_EQUALS,
+    #[error]
+    ERROR,
+    EOF,
+    SOURCE_FILE,
+    IMPORT_DECL,
+    LITERAL,
+    NAME,
+    OPTIONAL_TYPE,
+    PAREN_TYPE,
+    ASYNC_TYPE,
+    PATH_TYPE,
+    TUPLE_TYPE,
+    ARRAY_TYPE,
+    FUNC_TYPE,
+    OBJECT_TYPE,
+    VARIANT_TYPE,
+    NAMED_TYPE,
+    PRIM_TYPE,
+    AND_TYPE,
+    OR_TYPE,
+    ANY_TYPE,
+    NONE_TYPE,
+    NULL_TYPE,
+    ACTOR_CLASS_TYPE,
+    PATH,
+    TYPE_ARGS,
+    FUNC_SORT,
+    TYPE_PARAMS,
+    FUNC_ARG,
+    FUNC_RESULT,
+    TYPE_BIND,
+    TYPE_BOUND,
+    OBJECT_SORT,
+    TYPE_FIELD,
+    TYPE_FIELD_FUNC,
+    TYPE_ANNOTATION,
+    TYPE_TAG,
+    WILDCARD_PAT,
+    VAR_PAT,
+    LITERAL_PAT,
+    PAREN_PAT,
+    TUPLE_PAT,
+    OBJECT_PAT,
+    VARIANT_PAT,
+    OPTION_PAT,
+    ALT_PAT,
+    ANNOT_PAT,
+    PATTERN_FIELD_PUN,
+    PATTERN_FIELD_PAT,
+    TAG,
+    LITERAL_EXPR,
+    VAR_EXPR,
+    PAREN_EXPR,
+    TUPLE_EXPR,
+    PRIM_EXPR,
+    ARRAY_EXPR,
+    INDEX_EXPR,
+    PROJ_EXPR,
+    DOT_EXPR,
+    CALL_EXPR,
+    VARIANT_EXPR,
+    OPTION_EXPR,
+    UNARY_EXPR,
+    ACTOR_URL_EXPR,
+    SHOW_EXPR,
+    BIN_EXPR,
+    ANNOT_EXPR,
+    ASSIGN_EXPR,
+    RETURN_EXPR,
+    ASYNC_EXPR,
+    AWAIT_EXPR,
+    ASSERT_EXPR,
+    DEBUG_EXPR,
+    IF_EXPR,
+    SWITCH_EXPR,
+    TRY_EXPR,
+    THROW_EXPR,
+    WHILE_EXPR,
+    LOOP_EXPR,
+    FOR_EXPR,
+    LABEL_EXPR,
+    BREAK_EXPR,
+    CONTINUE_EXPR,
+    IGNORE_EXPR,
+    BLOCK_EXPR,
+    DO_EXPR,
+    OBJECT_EXPR,
+    LET_DECL,
+    VAR_DECL,
+    TYPE_DECL,
+    FUNC_DECL,
+    CLASS_DECL,
+    OBJECT_DECL,
+    CASE_ARM,
+    CATCH_CLAUSE,
+    EXP_FIELD,
+    EXP_DECL,
+    CLASS_BODY,
+    OBJECT_BODY,
+    DECL_FIELD,
+    VISIBILITY,
+    STABILITY,
+}
+impl SyntaxKind {
+    pub fn is_keyword(self) -> bool {
+        matches!(
+            self,
+            SyntaxKind::FUNC_KW
+                | SyntaxKind::LET_KW
+                | SyntaxKind::VAR_KW
+                | SyntaxKind::SWITCH_KW
+                | SyntaxKind::CASE_KW
+                | SyntaxKind::TRUE_KW
+                | SyntaxKind::FALSE_KW
+                | SyntaxKind::NULL_KW
+                | SyntaxKind::SHARED_KW
+                | SyntaxKind::QUERY_KW
+                | SyntaxKind::ASYNC_KW
+                | SyntaxKind::ASYNC_STAR_KW
+                | SyntaxKind::OBJECT_KW
+                | SyntaxKind::CLASS_KW
+                | SyntaxKind::ACTOR_KW
+                | SyntaxKind::MODULE_KW
+                | SyntaxKind::PRIM_KW
+                | SyntaxKind::IF_KW
+                | SyntaxKind::ELSE_KW
+                | SyntaxKind::WHILE_KW
+                | SyntaxKind::LOOP_KW
+                | SyntaxKind::FOR_KW
+                | SyntaxKind::IN_KW
+                | SyntaxKind::RETURN_KW
+                | SyntaxKind::AWAIT_KW
+                | SyntaxKind::AWAIT_STAR_KW
+                | SyntaxKind::ASSERT_KW
+                | SyntaxKind::DEBUG_KW
+                | SyntaxKind::DEBUG_SHOW_KW
+                | SyntaxKind::IGNORE_KW
+                | SyntaxKind::AND_KW
+                | SyntaxKind::OR_KW
+                | SyntaxKind::NOT_KW
+                | SyntaxKind::LABEL_KW
+                | SyntaxKind::BREAK_KW
+                | SyntaxKind::CONTINUE_KW
+                | SyntaxKind::TRY_KW
+                | SyntaxKind::CATCH_KW
+                | SyntaxKind::THROW_KW
+                | SyntaxKind::DO_KW
+                | SyntaxKind::WITH_KW
+                | SyntaxKind::IMPORT_KW
+                | SyntaxKind::PUBLIC_KW
+                | SyntaxKind::PRIVATE_KW
+                | SyntaxKind::SYSTEM_KW
+                | SyntaxKind::STABLE_KW
+                | SyntaxKind::FLEXIBLE_KW
+                | SyntaxKind::TYPE_KW
+        )
+    }
+    pub fn is_punct(self) -> bool {
+        matches!(
+            self,
+            SyntaxKind::DOT
+                | SyntaxKind::PLUS
+                | SyntaxKind::MINUS
+                | SyntaxKind::UNDERSCORE
+                | SyntaxKind::STAR
+                | SyntaxKind::SLASH
+                | SyntaxKind::EQUALS
+                | SyntaxKind::DOUBLE_EQUALS
+                | SyntaxKind::L_PAREN
+                | SyntaxKind::R_PAREN
+                | SyntaxKind::L_BRACKET
+                | SyntaxKind::R_BRACKET
+                | SyntaxKind::L_BRACE
+                | SyntaxKind::R_BRACE
+                | SyntaxKind::L_ANGLE
+                | SyntaxKind::R_ANGLE
+                | SyntaxKind::SUB
+                | SyntaxKind::HASH
+                | SyntaxKind::QUESTION
+                | SyntaxKind::BANG
+                | SyntaxKind::COLON
+                | SyntaxKind::SEMICOLON
+                | SyntaxKind::COMMA
+                | SyntaxKind::ARROW
+                | SyntaxKind::NOT_EQUALS
+                | SyntaxKind::PERCENT
+                | SyntaxKind::DOUBLE_STAR
+                | SyntaxKind::AMPERSAND
+                | SyntaxKind::PIPE
+                | SyntaxKind::CARET
+                | SyntaxKind::PIPE_GREATER
+                | SyntaxKind::COLON_EQUALS
+                | SyntaxKind::PLUS_EQUALS
+                | SyntaxKind::MINUS_EQUALS
+                | SyntaxKind::STAR_EQUALS
+                | SyntaxKind::SLASH_EQUALS
+                | SyntaxKind::PERCENT_EQUALS
+                | SyntaxKind::DOUBLE_STAR_EQUALS
+                | SyntaxKind::AMPERSAND_EQUALS
+                | SyntaxKind::PIPE_EQUALS
+                | SyntaxKind::CARET_EQUALS
+                | SyntaxKind::HASH_EQUALS
+                | SyntaxKind::LESS_EQUALS
+                | SyntaxKind::GREATER_EQUALS
+                | SyntaxKind::SHL
+                | SyntaxKind::USHR
+                | SyntaxKind::SSHR
+                | SyntaxKind::ROTL
+                | SyntaxKind::ROTR
+                | SyntaxKind::SHL_EQUALS
+                | SyntaxKind::USHR_EQUALS
+                | SyntaxKind::SSHR_EQUALS
+                | SyntaxKind::ROTL_EQUALS
+                | SyntaxKind::ROTR_EQUALS
+        )
+    }
+    pub fn is_literal(self) -> bool {
+        matches!(
+            self,
+            SyntaxKind::NAT_LIT
+                | SyntaxKind::FLOAT_LIT
+                | SyntaxKind::TEXT_LIT
+                | SyntaxKind::CHAR_LIT
+        )
+    }
+}
+#[macro_export]
+macro_rules! T {
+    [.] => { $crate::lexer::SyntaxKind::DOT };
+    [+] => { $crate::lexer::SyntaxKind::PLUS };
+    [-] => { $crate::lexer::SyntaxKind::MINUS };
+    [_] => { $crate::lexer::SyntaxKind::UNDERSCORE };
+    [*] => { $crate::lexer::SyntaxKind::STAR };
+    [/] => { $crate::lexer::SyntaxKind::SLASH };
+    [=] => { $crate::lexer::SyntaxKind::EQUALS };
+    [==] => { $crate::lexer::SyntaxKind::DOUBLE_EQUALS };
+    ['('] => { $crate::lexer::SyntaxKind::L_PAREN };
+    [')'] => { $crate::lexer::SyntaxKind::R_PAREN };
+    ['['] => { $crate::lexer::SyntaxKind::L_BRACKET };
+    [']'] => { $crate::lexer::SyntaxKind::R_BRACKET };
+    ['{'] => { $crate::lexer::SyntaxKind::L_BRACE };
+    ['}'] => { $crate::lexer::SyntaxKind::R_BRACE };
+    [<] => { $crate::lexer::SyntaxKind::L_ANGLE };
+    [>] => { $crate::lexer::SyntaxKind::R_ANGLE };
+    [<:] => { $crate::lexer::SyntaxKind::SUB };
+    [#] => { $crate::lexer::SyntaxKind::HASH };
+    [?] => { $crate::lexer::SyntaxKind::QUESTION };
+    [!] => { $crate::lexer::SyntaxKind::BANG };
+    [:] => { $crate::lexer::SyntaxKind::COLON };
+    [;] => { $crate::lexer::SyntaxKind::SEMICOLON };
+    [,] => { $crate::lexer::SyntaxKind::COMMA };
+    [->] => { $crate::lexer::SyntaxKind::ARROW };
+    [!=] => { $crate::lexer::SyntaxKind::NOT_EQUALS };
+    [%] => { $crate::lexer::SyntaxKind::PERCENT };
+    [**] => { $crate::lexer::SyntaxKind::DOUBLE_STAR };
+    [&] => { $crate::lexer::SyntaxKind::AMPERSAND };
+    [|] => { $crate::lexer::SyntaxKind::PIPE };
+    [^] => { $crate::lexer::SyntaxKind::CARET };
+    [|>] => { $crate::lexer::SyntaxKind::PIPE_GREATER };
+    [:=] => { $crate::lexer::SyntaxKind::COLON_EQUALS };
+    [+=] => { $crate::lexer::SyntaxKind::PLUS_EQUALS };
+    [-=] => { $crate::lexer::SyntaxKind::MINUS_EQUALS };
+    [*=] => { $crate::lexer::SyntaxKind::STAR_EQUALS };
+    [/=] => { $crate::lexer::SyntaxKind::SLASH_EQUALS };
+    [%=] => { $crate::lexer::SyntaxKind::PERCENT_EQUALS };
+    [**=] => { $crate::lexer::SyntaxKind::DOUBLE_STAR_EQUALS };
+    [&=] => { $crate::lexer::SyntaxKind::AMPERSAND_EQUALS };
+    [|=] => { $crate::lexer::SyntaxKind::PIPE_EQUALS };
+    [^=] => { $crate::lexer::SyntaxKind::CARET_EQUALS };
+    [#=] => { $crate::lexer::SyntaxKind::HASH_EQUALS };
+    [<=] => { $crate::lexer::SyntaxKind::LESS_EQUALS };
+    [>=] => { $crate::lexer::SyntaxKind::GREATER_EQUALS };
+    [<<] => { $crate::lexer::SyntaxKind::SHL };
+    [>>] => { $crate::lexer::SyntaxKind::USHR };
+    [+>>] => { $crate::lexer::SyntaxKind::SSHR };
+    [<<>] => { $crate::lexer::SyntaxKind::ROTL };
+    [<>>] => { $crate::lexer::SyntaxKind::ROTR };
+    [<<=] => { $crate::lexer::SyntaxKind::SHL_EQUALS };
+    [>>=] => { $crate::lexer::SyntaxKind::USHR_EQUALS };
+    [+>>=] => { $crate::lexer::SyntaxKind::SSHR_EQUALS };
+    [<<>=] => { $crate::lexer::SyntaxKind::ROTL_EQUALS };
+    [<>>=] => { $crate::lexer::SyntaxKind::ROTR_EQUALS };
+    [func] => { $crate::lexer::SyntaxKind::FUNC_KW };
+    [let] => { $crate::lexer::SyntaxKind::LET_KW };
+    [var] => { $crate::lexer::SyntaxKind::VAR_KW };
+    [switch] => { $crate::lexer::SyntaxKind::SWITCH_KW };
+    [case] => { $crate::lexer::SyntaxKind::CASE_KW };
+    [true] => { $crate::lexer::SyntaxKind::TRUE_KW };
+    [false] => { $crate::lexer::SyntaxKind::FALSE_KW };
+    [null] => { $crate::lexer::SyntaxKind::NULL_KW };
+    [shared] => { $crate::lexer::SyntaxKind::SHARED_KW };
+    [query] => { $crate::lexer::SyntaxKind::QUERY_KW };
+    [async] => { $crate::lexer::SyntaxKind::ASYNC_KW };
+    [async*] => { $crate::lexer::SyntaxKind::ASYNC_STAR_KW };
+    [object] => { $crate::lexer::SyntaxKind::OBJECT_KW };
+    [class] => { $crate::lexer::SyntaxKind::CLASS_KW };
+    [actor] => { $crate::lexer::SyntaxKind::ACTOR_KW };
+    [module] => { $crate::lexer::SyntaxKind::MODULE_KW };
+    [prim] => { $crate::lexer::SyntaxKind::PRIM_KW };
+    [if] => { $crate::lexer::SyntaxKind::IF_KW };
+    [else] => { $crate::lexer::SyntaxKind::ELSE_KW };
+    [while] => { $crate::lexer::SyntaxKind::WHILE_KW };
+    [loop] => { $crate::lexer::SyntaxKind::LOOP_KW };
+    [for] => { $crate::lexer::SyntaxKind::FOR_KW };
+    [in] => { $crate::lexer::SyntaxKind::IN_KW };
+    [return] => { $crate::lexer::SyntaxKind::RETURN_KW };
+    [await] => { $crate::lexer::SyntaxKind::AWAIT_KW };
+    [await*] => { $crate::lexer::SyntaxKind::AWAIT_STAR_KW };
+    [assert] => { $crate::lexer::SyntaxKind::ASSERT_KW };
+    [debug] => { $crate::lexer::SyntaxKind::DEBUG_KW };
+    [debug_show] => { $crate::lexer::SyntaxKind::DEBUG_SHOW_KW };
+    [ignore] => { $crate::lexer::SyntaxKind::IGNORE_KW };
+    [and] => { $crate::lexer::SyntaxKind::AND_KW };
+    [or] => { $crate::lexer::SyntaxKind::OR_KW };
+    [not] => { $crate::lexer::SyntaxKind::NOT_KW };
+    [label] => { $crate::lexer::SyntaxKind::LABEL_KW };
+    [break] => { $crate::lexer::SyntaxKind::BREAK_KW };
+    [continue] => { $crate::lexer::SyntaxKind::CONTINUE_KW };
+    [try] => { $crate::lexer::SyntaxKind::TRY_KW };
+    [catch] => { $crate::lexer::SyntaxKind::CATCH_KW };
+    [throw] => { $crate::lexer::SyntaxKind::THROW_KW };
+    [do] => { $crate::lexer::SyntaxKind::DO_KW };
+    [with] => { $crate::lexer::SyntaxKind::WITH_KW };
+    [import] => { $crate::lexer::SyntaxKind::IMPORT_KW };
+    [public] => { $crate::lexer::SyntaxKind::PUBLIC_KW };
+    [private] => { $crate::lexer::SyntaxKind::PRIVATE_KW };
+    [system] => { $crate::lexer::SyntaxKind::SYSTEM_KW };
+    [stable] => { $crate::lexer::SyntaxKind::STABLE_KW };
+    [flexible] => { $crate::lexer::SyntaxKind::FLEXIBLE_KW };
+    [type] => { $crate::lexer::SyntaxKind::TYPE_KW };
+    [nat_lit] => { $crate::lexer::SyntaxKind::NAT_LIT };
+    [float_lit] => { $crate::lexer::SyntaxKind::FLOAT_LIT };
+    [text_lit] => { $crate::lexer::SyntaxKind::TEXT_LIT };
+    [char_lit] => { $crate::lexer::SyntaxKind::CHAR_LIT };
+    [ident] => { $crate::lexer::SyntaxKind::IDENT };
+    [dot_num] => { $crate::lexer::SyntaxKind::DOT_NUM };
+}
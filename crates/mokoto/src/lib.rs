@@ -1,3 +1,4 @@
+pub mod diagnostic;
 pub mod lexer;
 pub mod parser;
 pub mod syntax;
@@ -5,17 +5,22 @@ mod patterns;
 mod token_set;
 mod types;
 
+use crate::diagnostic::{Diagnostic, SYNTAX_ERROR};
 use crate::lexer::{Lexer, SyntaxKind, SyntaxKind::*, Token};
 use crate::syntax::{MotokoLanguage, SyntaxNode};
-use declaration::decl;
-use rowan::{Checkpoint, GreenNode, GreenNodeBuilder, Language};
+use rowan::{Checkpoint, GreenNode, GreenNodeBuilder, Language, TextRange, TextSize};
 use token_set::TokenSet;
 
 pub struct Parser<'a> {
     tokens: Vec<Token<'a>>,
     builder: GreenNodeBuilder<'static>,
-    // TODO: Be smarter here
-    errors: Vec<String>,
+    // Whether the last token we bumped was followed by trivia
+    trailing_trivia: bool,
+    // The offset of the next token, including its leading trivia
+    offset: TextSize,
+    // The end of the last token we bumped, without its trailing trivia
+    last_end: TextSize,
+    errors: Vec<Diagnostic>,
 }
 
 impl<'a> Parser<'a> {
@@ -25,14 +30,22 @@ impl<'a> Parser<'a> {
         Self {
             tokens: tkns,
             builder: GreenNodeBuilder::new(),
+            trailing_trivia: false,
+            offset: TextSize::from(0),
+            last_end: TextSize::from(0),
             errors: vec![],
         }
     }
 
-    pub fn parse(mut self) -> Parse {
-        self.start_node(SyntaxKind::Root);
+    /// parse_prog
+    ///
+    /// Parses a whole file into a `SourceFile`, its imports followed by its
+    /// declarations.
+    pub fn parse_prog(mut self) -> Parse {
+        self.start_node(SOURCE_FILE);
 
-        decl(&mut self);
+        declaration::prog(&mut self);
+        self.bump_eof_trivia();
 
         self.finish_node();
 
@@ -42,8 +55,22 @@ impl<'a> Parser<'a> {
         }
     }
 
-    pub fn parse_typ(mut self) -> Parse {
-        types::typ(&mut self);
+    /// Programs used to be parsed into a `Root` of declarations, they are a
+    /// `SourceFile` now.
+    #[deprecated(note = "use `parse_prog`")]
+    pub fn parse(self) -> Parse {
+        self.parse_prog()
+    }
+
+    /// parse_module_header
+    ///
+    /// Only parses the imports at the start of the input, for tools that need
+    /// a file's dependencies but not its contents. The tree ends after the
+    /// last import.
+    pub fn parse_module_header(mut self) -> Parse {
+        self.start_node(SOURCE_FILE);
+        declaration::imports(&mut self);
+        self.finish_node();
 
         Parse {
             green_node: self.builder.finish(),
@@ -51,8 +78,34 @@ impl<'a> Parser<'a> {
         }
     }
 
-    pub fn parse_pattern(mut self) -> Parse {
-        patterns::pattern(&mut self);
+    pub fn parse_typ(self) -> Parse {
+        self.parse_fragment(types::typ)
+    }
+
+    pub fn parse_pattern(self) -> Parse {
+        self.parse_fragment(patterns::pattern)
+    }
+
+    pub fn parse_expr(self) -> Parse {
+        self.parse_fragment(expression::expr)
+    }
+
+    /// Parses the input with `f`. Like rust-analyzer, anything `f` leaves over
+    /// is wrapped together with its result in an `ERROR` node, so every input
+    /// produces a tree.
+    fn parse_fragment(mut self, f: fn(&mut Parser)) -> Parse {
+        let c = self.checkpoint();
+        f(&mut self);
+        if !self.at(EOF) || self.offset == TextSize::from(0) {
+            if !self.at(EOF) {
+                self.error("Expected the end of the input");
+            }
+            while !self.at(EOF) {
+                self.bump_any();
+            }
+            self.bump_eof_trivia();
+            self.finish_at(c, ERROR);
+        }
 
         Parse {
             green_node: self.builder.finish(),
@@ -82,23 +135,78 @@ impl<'a> Parser<'a> {
         self.builder.checkpoint()
     }
 
+    fn token(&mut self, kind: SyntaxKind, text: &str) {
+        self.offset += TextSize::of(text);
+        self.builder
+            .token(MotokoLanguage::kind_to_raw(kind), text.into());
+    }
+
     fn bump_any(&mut self) {
-        let (leading, (kind, text), trailing) = self.tokens.pop().unwrap();
+        if self.at(EOF) {
+            return;
+        }
+        let (leading, (kind, text), trailing) = match self.tokens.pop() {
+            Some(tkn) => tkn,
+            None => return,
+        };
 
         for (kind, text) in leading {
-            self.builder
-                .token(MotokoLanguage::kind_to_raw(kind), text.into());
+            self.token(kind, text);
         }
 
-        self.builder
-            .token(MotokoLanguage::kind_to_raw(kind), text.into());
+        self.token(kind, text);
+        self.last_end = self.offset;
 
+        self.trailing_trivia = !trailing.is_empty();
         for (kind, text) in trailing {
-            self.builder
-                .token(MotokoLanguage::kind_to_raw(kind), text.into());
+            self.token(kind, text);
         }
     }
 
+    /// Bumps the next `n` tokens as a single token of the given `kind`. Used
+    /// for operators the lexer splits up, see `at_composite`.
+    fn bump_composite(&mut self, kind: SyntaxKind, n: usize) {
+        if n == 1 {
+            return self.bump_any();
+        }
+        let mut joined = String::new();
+        let mut trailing = vec![];
+        for i in 0..n {
+            let (leading, (_, text), tr) = match self.tokens.pop() {
+                Some(tkn) => tkn,
+                None => break,
+            };
+            if i == 0 {
+                for (kind, text) in leading {
+                    self.token(kind, text);
+                }
+            }
+            joined.push_str(text);
+            trailing = tr;
+        }
+
+        self.token(kind, &joined);
+        self.last_end = self.offset;
+
+        self.trailing_trivia = !trailing.is_empty();
+        for (kind, text) in trailing {
+            self.token(kind, text);
+        }
+    }
+
+    /// Trivia before the end of the input is only left over if the input
+    /// consists of nothing but trivia.
+    fn bump_eof_trivia(&mut self) {
+        let leading = match self.tokens.last_mut() {
+            Some((leading, _, _)) => std::mem::take(leading),
+            None => return,
+        };
+        for (kind, text) in leading {
+            self.token(kind, text);
+        }
+    }
+
+    /// Callers must have checked that the current token is `kind`
     fn bump(&mut self, kind: SyntaxKind) {
         assert!(self.eat(kind))
     }
@@ -128,22 +236,159 @@ impl<'a> Parser<'a> {
         self.nth(0)
     }
 
+    /// The text of the current token, for identifiers that mean something
+    /// special in some positions
+    fn current_text(&self) -> &'a str {
+        self.nth_token(0).map_or("", |(_, (_, text), _)| text)
+    }
+
     fn at(&self, kind: SyntaxKind) -> bool {
         self.nth_at(0, kind)
     }
 
+    fn nth_token(&self, n: usize) -> Option<&Token<'a>> {
+        let len = self.tokens.len();
+        if n >= len {
+            None
+        } else {
+            Some(&self.tokens[len - n - 1])
+        }
+    }
+
+    /// Checks whether the next tokens are `kinds`, with no trivia between them.
+    fn at_composite(&self, kinds: &[SyntaxKind]) -> bool {
+        for (i, kind) in kinds.iter().enumerate() {
+            if !self.nth_at(i, *kind) {
+                return false;
+            }
+            if i > 0 {
+                let (_, _, trailing) = self.nth_token(i - 1).unwrap();
+                let (leading, _, _) = self.nth_token(i).unwrap();
+                if !trailing.is_empty() || !leading.is_empty() {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Checks whether the current token is surrounded by trivia on both sides.
+    /// Motoko requires this for `<` and `>` used as binary operators.
+    fn at_spaced(&self) -> bool {
+        let (leading, _, trailing) = match self.nth_token(0) {
+            Some(tkn) => tkn,
+            None => return false,
+        };
+        let preceded_by_trivia = self.trailing_trivia || !leading.is_empty();
+        let followed_by_trivia = !trailing.is_empty()
+            || self
+                .nth_token(1)
+                .is_some_and(|(leading, _, _)| !leading.is_empty());
+        preceded_by_trivia && followed_by_trivia
+    }
+
     fn at_ts(&mut self, kinds: TokenSet) -> bool {
         kinds.contains(self.current())
     }
 
+    /// The range of the current token, without its trivia. Empty at the end
+    /// of the input.
+    fn current_range(&self) -> TextRange {
+        match self.nth_token(0) {
+            Some((leading, (_, text), _)) => {
+                let start = self.offset
+                    + leading
+                        .iter()
+                        .map(|(_, text)| TextSize::of(*text))
+                        .sum::<TextSize>();
+                TextRange::at(start, TextSize::of(*text))
+            }
+            None => TextRange::empty(self.offset),
+        }
+    }
+
+    /// The range from `start` to the end of the last token we bumped, for
+    /// diagnostics about everything parsed since `start`
+    fn range_from(&self, start: TextSize) -> TextRange {
+        TextRange::new(start, self.last_end.max(start))
+    }
+
     fn error(&mut self, msg: &str) {
-        self.errors.push(msg.to_string())
+        let range = self.current_range();
+        // Further errors at the same position are most likely caused by the
+        // first one
+        if self
+            .errors
+            .last()
+            .is_some_and(|e| e.is_error() && e.range == range)
+        {
+            return;
+        }
+        self.errors
+            .push(Diagnostic::error(SYNTAX_ERROR, range, msg))
+    }
+
+    /// Reports a warning about syntax that parses, but should be written
+    /// differently
+    fn warning(&mut self, msg: &str, range: TextRange) {
+        self.errors.push(Diagnostic::warning(range, msg))
+    }
+
+    /// Reports an error and wraps the current token in an `ERROR` node, unless
+    /// it is in `recovery` and some enclosing rule can continue from it.
+    fn err_recover(&mut self, msg: &str, recovery: TokenSet) {
+        if self.at_ts(recovery.union(ALWAYS_RECOVER)) {
+            self.error(msg);
+            return;
+        }
+        self.err_and_bump(msg)
+    }
+
+    /// Reports an error and wraps the current token in an `ERROR` node
+    fn err_and_bump(&mut self, msg: &str) {
+        self.error(msg);
+        self.start_node(ERROR);
+        self.bump_any();
+        self.finish_node();
+    }
+
+    /// Wraps all tokens up to the next one in `recovery` in an `ERROR` node.
+    /// Braces are skipped in pairs, so recovery never leaves the current block.
+    fn skip_until(&mut self, recovery: TokenSet) {
+        if self.at_ts(recovery.union(ALWAYS_RECOVER)) {
+            return;
+        }
+        self.start_node(ERROR);
+        let mut depth = 0;
+        while !self.at(EOF) {
+            match self.current() {
+                L_BRACE => depth += 1,
+                R_BRACE if depth == 0 => break,
+                R_BRACE => depth -= 1,
+                t if depth == 0 && recovery.contains(t) => break,
+                _ => {}
+            }
+            self.bump_any();
+        }
+        self.finish_node();
+    }
+
+    /// Reports a missing closing delimiter, pointing back at the one it was
+    /// supposed to close.
+    fn error_unclosed(&mut self, msg: &str, open: TextRange) {
+        let range = self.current_range();
+        self.errors.push(
+            Diagnostic::error(SYNTAX_ERROR, range, msg).with_label(open, "unclosed delimiter"),
+        )
     }
 }
 
+/// Tokens we never skip during error recovery
+const ALWAYS_RECOVER: TokenSet = TokenSet::new(&[L_BRACE, R_BRACE, EOF]);
+
 pub struct Parse {
     green_node: GreenNode,
-    errors: Vec<String>,
+    errors: Vec<Diagnostic>,
 }
 
 impl Parse {
@@ -151,11 +396,20 @@ impl Parse {
         SyntaxNode::new_root(self.green_node.clone())
     }
 
+    pub fn errors(&self) -> &[Diagnostic] {
+        &self.errors
+    }
+
     pub fn debug_tree(&self) -> String {
         let syntax_node = SyntaxNode::new_root(self.green_node.clone());
-        let formatted = format!("{:#?}", syntax_node);
+        let mut formatted = format!("{:#?}", syntax_node);
 
         // We cut off the last byte because formatting the SyntaxNode adds on a newline at the end.
-        formatted[0..formatted.len() - 1].to_string()
+        formatted.pop();
+
+        for error in &self.errors {
+            formatted.push_str(&format!("\n{}", error));
+        }
+        formatted
     }
 }
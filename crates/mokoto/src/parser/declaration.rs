@@ -1,23 +1,171 @@
-use super::expression;
+use super::expression::{self, BraceMode};
+use super::literals::literal;
 use super::patterns;
-use super::Parser;
+use super::types::{opt_annot, opt_typ_params, typ};
+use super::{Checkpoint, Parser, TokenSet};
 use crate::lexer::SyntaxKind;
 
+/// parse_prog
+pub(super) fn prog(p: &mut Parser) {
+    imports(p);
+    while !p.at(SyntaxKind::EOF) {
+        // Recovery stops at braces, but at the top level there's no block
+        // to close
+        if p.at(SyntaxKind::R_BRACE) {
+            p.err_and_bump("Unmatched }");
+            continue;
+        }
+        decl(p);
+        if p.eat(SyntaxKind::SEMICOLON) || p.at(SyntaxKind::EOF) {
+            continue;
+        }
+        p.error("Expected a ;");
+        p.skip_until(DECL_RECOVERY);
+        p.eat(SyntaxKind::SEMICOLON);
+    }
+}
+
+/// Where to continue after a malformed declaration
+pub(super) const DECL_RECOVERY: TokenSet = TokenSet::new(&[
+    SyntaxKind::SEMICOLON,
+    SyntaxKind::LET_KW,
+    SyntaxKind::VAR_KW,
+    SyntaxKind::TYPE_KW,
+    SyntaxKind::SHARED_KW,
+    SyntaxKind::QUERY_KW,
+    SyntaxKind::FUNC_KW,
+    SyntaxKind::CLASS_KW,
+    SyntaxKind::OBJECT_KW,
+    SyntaxKind::ACTOR_KW,
+    SyntaxKind::MODULE_KW,
+]);
+
+/// The `imp*` at the start of a program
+pub(super) fn imports(p: &mut Parser) {
+    while p.at(SyntaxKind::IMPORT_KW) {
+        import(p);
+        if !p.eat(SyntaxKind::SEMICOLON) {
+            break;
+        }
+    }
+}
+
+/// imp
+fn import(p: &mut Parser) {
+    p.start_node(SyntaxKind::IMPORT_DECL);
+    p.bump(SyntaxKind::IMPORT_KW);
+    if !p.at(SyntaxKind::EQUALS) && !p.at(SyntaxKind::TEXT_LIT) {
+        patterns::pat_nullary(p);
+    }
+    p.eat(SyntaxKind::EQUALS);
+    if p.at(SyntaxKind::TEXT_LIT) {
+        literal(p);
+    } else {
+        p.error("Expected an import path");
+    }
+    p.finish_node();
+}
+
+/// dec
 pub(super) fn decl(p: &mut Parser) {
+    if p.at(SyntaxKind::VAR_KW) {
+        var_decl(p)
+    } else if at_decl_nonvar(p) {
+        decl_nonvar(p)
+    } else {
+        let c = p.checkpoint();
+        expression::expr_nondec(p, BraceMode::Object);
+        p.finish_at(c, SyntaxKind::EXP_DECL)
+    }
+}
+
+const OBJ_SORTS: TokenSet = TokenSet::new(&[
+    SyntaxKind::OBJECT_KW,
+    SyntaxKind::ACTOR_KW,
+    SyntaxKind::MODULE_KW,
+]);
+
+/// Whether we're at the start of a `dec_nonvar`. `actor` also starts actor
+/// reference expressions like `actor "aaaaa-aa"`, so we need to look at the
+/// token after it.
+pub(super) fn at_decl_nonvar(p: &Parser) -> bool {
+    match p.current() {
+        SyntaxKind::LET_KW
+        | SyntaxKind::TYPE_KW
+        | SyntaxKind::SHARED_KW
+        | SyntaxKind::QUERY_KW
+        | SyntaxKind::FUNC_KW
+        | SyntaxKind::CLASS_KW
+        | SyntaxKind::OBJECT_KW
+        | SyntaxKind::MODULE_KW => true,
+        SyntaxKind::ACTOR_KW => matches!(
+            p.nth(1),
+            SyntaxKind::CLASS_KW | SyntaxKind::IDENT | SyntaxKind::EQUALS | SyntaxKind::L_BRACE
+        ),
+        _ => false,
+    }
+}
+
+/// dec_nonvar
+pub(super) fn decl_nonvar(p: &mut Parser) {
     match p.current() {
         SyntaxKind::LET_KW => let_decl(p),
-        _ => unreachable!("Unknown decl."),
+        SyntaxKind::TYPE_KW => type_decl(p),
+        _ => {
+            let c = p.checkpoint();
+            let shared = opt_shared_pat(p);
+            if p.at(SyntaxKind::FUNC_KW) {
+                return func_decl(p, c);
+            }
+            if p.at_ts(OBJ_SORTS) {
+                let sort = p.checkpoint();
+                p.bump_any();
+                p.finish_at(sort, SyntaxKind::OBJECT_SORT);
+            }
+            // Only functions and classes can be shared
+            if shared || p.at(SyntaxKind::CLASS_KW) {
+                class_decl(p, c)
+            } else {
+                object_decl(p, c)
+            }
+        }
+    }
+}
+
+/// shared_pat_opt, like `shared query ({ caller })`
+fn opt_shared_pat(p: &mut Parser) -> bool {
+    let c = p.checkpoint();
+    if p.eat(SyntaxKind::SHARED_KW) {
+        p.eat(SyntaxKind::QUERY_KW);
+    } else if !p.eat(SyntaxKind::QUERY_KW) {
+        return false;
     }
+    // The caller pattern is optional
+    if p.at(SyntaxKind::L_BRACE) {
+        let start = p.current_range().start();
+        patterns::pat_nullary(p);
+        p.warning(
+            "object syntax is deprecated in this position, use '({...})'",
+            p.range_from(start),
+        );
+    } else {
+        let _ = patterns::pat_plain(p);
+    }
+    p.finish_at(c, SyntaxKind::FUNC_SORT);
+    true
 }
 
-fn ident(p: &mut Parser) {
-    if !p.eat(SyntaxKind::IDENT) {
+pub(super) fn name(p: &mut Parser) {
+    let c = p.checkpoint();
+    if p.eat(SyntaxKind::IDENT) {
+        p.finish_at(c, SyntaxKind::NAME)
+    } else {
         p.error("Expected an ident")
     }
 }
 
-fn let_decl(p: &mut Parser) {
-    p.start_node(SyntaxKind::LetD);
+pub(super) fn let_decl(p: &mut Parser) {
+    p.start_node(SyntaxKind::LET_DECL);
     p.bump(SyntaxKind::LET_KW);
     patterns::pattern(p);
     if !p.eat(SyntaxKind::EQUALS) {
@@ -26,3 +174,138 @@ fn let_decl(p: &mut Parser) {
     expression::expr(p);
     p.finish_node();
 }
+
+pub(super) fn var_decl(p: &mut Parser) {
+    p.start_node(SyntaxKind::VAR_DECL);
+    p.bump(SyntaxKind::VAR_KW);
+    name(p);
+    opt_annot(p);
+    if !p.eat(SyntaxKind::EQUALS) {
+        p.error("Expected an =");
+    }
+    expression::expr(p);
+    p.finish_node();
+}
+
+fn type_decl(p: &mut Parser) {
+    p.start_node(SyntaxKind::TYPE_DECL);
+    p.bump(SyntaxKind::TYPE_KW);
+    name(p);
+    opt_typ_params(p);
+    if !p.eat(SyntaxKind::EQUALS) {
+        p.error("Expected an =");
+    }
+    typ(p);
+    p.finish_node();
+}
+
+/// Expects the optional function sort to be parsed already, starting at `c`
+fn func_decl(p: &mut Parser, c: Checkpoint) {
+    p.bump(SyntaxKind::FUNC_KW);
+    // Function expressions don't have a name
+    if p.at(SyntaxKind::IDENT) {
+        name(p);
+    }
+    func_params(p);
+    func_body(p);
+    p.finish_at(c, SyntaxKind::FUNC_DECL)
+}
+
+/// The `typ_params_opt pat_plain annot_opt` shared by functions and classes
+fn func_params(p: &mut Parser) {
+    opt_typ_params(p);
+    if !patterns::pat_plain(p) {
+        p.error("Expected a parameter pattern");
+    }
+    opt_annot(p);
+}
+
+/// func_body
+fn func_body(p: &mut Parser) {
+    if p.eat(SyntaxKind::EQUALS) {
+        expression::expr(p)
+    } else if p.at(SyntaxKind::L_BRACE) {
+        expression::block(p)
+    } else {
+        p.error("Expected a function body");
+    }
+}
+
+/// Expects the optional function and object sorts to be parsed already,
+/// starting at `c`
+fn class_decl(p: &mut Parser, c: Checkpoint) {
+    if !p.eat(SyntaxKind::CLASS_KW) {
+        p.error("Expected func or class");
+    }
+    if p.at(SyntaxKind::IDENT) {
+        name(p);
+    }
+    func_params(p);
+    let body = p.checkpoint();
+    if p.eat(SyntaxKind::EQUALS) && p.at(SyntaxKind::IDENT) {
+        name(p);
+    }
+    obj_body(p);
+    p.finish_at(body, SyntaxKind::CLASS_BODY);
+    p.finish_at(c, SyntaxKind::CLASS_DECL)
+}
+
+/// Expects the object sort to be parsed already, starting at `c`
+fn object_decl(p: &mut Parser, c: Checkpoint) {
+    if p.at(SyntaxKind::IDENT) {
+        name(p);
+    }
+    p.eat(SyntaxKind::EQUALS);
+    obj_body(p);
+    p.finish_at(c, SyntaxKind::OBJECT_DECL)
+}
+
+/// obj_body
+fn obj_body(p: &mut Parser) {
+    let c = p.checkpoint();
+    let open = p.current_range();
+    if !p.eat(SyntaxKind::L_BRACE) {
+        p.error("Expected a {");
+        return;
+    }
+    while !p.at(SyntaxKind::R_BRACE) && !p.at(SyntaxKind::EOF) {
+        decl_field(p);
+        if p.eat(SyntaxKind::SEMICOLON) || p.at(SyntaxKind::R_BRACE) {
+            continue;
+        }
+        p.error("Expected a ;");
+        p.skip_until(FIELD_RECOVERY);
+        p.eat(SyntaxKind::SEMICOLON);
+    }
+    if !p.eat(SyntaxKind::R_BRACE) {
+        p.error_unclosed("Expected a }", open);
+    }
+    p.finish_at(c, SyntaxKind::OBJECT_BODY)
+}
+
+const VISIBILITY: TokenSet = TokenSet::new(&[
+    SyntaxKind::PUBLIC_KW,
+    SyntaxKind::PRIVATE_KW,
+    SyntaxKind::SYSTEM_KW,
+]);
+
+const STABILITY: TokenSet = TokenSet::new(&[SyntaxKind::STABLE_KW, SyntaxKind::FLEXIBLE_KW]);
+
+/// Where to continue after a malformed field in an object body
+const FIELD_RECOVERY: TokenSet = DECL_RECOVERY.union(VISIBILITY).union(STABILITY);
+
+/// dec_field
+fn decl_field(p: &mut Parser) {
+    let c = p.checkpoint();
+    if p.at_ts(VISIBILITY) {
+        p.bump_any();
+        p.finish_at(c, SyntaxKind::VISIBILITY);
+    }
+    if p.at_ts(STABILITY) {
+        let s = p.checkpoint();
+        p.bump_any();
+        p.finish_at(s, SyntaxKind::STABILITY);
+    }
+    decl(p);
+    p.finish_at(c, SyntaxKind::DECL_FIELD)
+}
@@ -1,9 +1,474 @@
 mod atom;
+use super::declaration::{at_decl_nonvar, decl, decl_nonvar, name, var_decl, DECL_RECOVERY};
+use super::literals::{literal, STARTS_LIT};
+use super::patterns;
+use super::types::{opt_annot, opt_annot_nobin, opt_typ_args};
 use super::*;
+pub(super) use atom::block;
 
+const STARTS_EXPR: TokenSet = TokenSet::new(&[
+    L_BRACKET,
+    HASH,
+    QUESTION,
+    PLUS,
+    MINUS,
+    CARET,
+    PLUS_EQUALS,
+    MINUS_EQUALS,
+    CARET_EQUALS,
+    NOT_KW,
+    ACTOR_KW,
+    DEBUG_SHOW_KW,
+    RETURN_KW,
+    ASYNC_KW,
+    ASYNC_STAR_KW,
+    AWAIT_KW,
+    AWAIT_STAR_KW,
+    ASSERT_KW,
+    DEBUG_KW,
+    IF_KW,
+    SWITCH_KW,
+    TRY_KW,
+    THROW_KW,
+    WHILE_KW,
+    LOOP_KW,
+    FOR_KW,
+    LABEL_KW,
+    BREAK_KW,
+    CONTINUE_KW,
+    IGNORE_KW,
+    DO_KW,
+    LET_KW,
+    VAR_KW,
+    TYPE_KW,
+    FUNC_KW,
+    CLASS_KW,
+    SHARED_KW,
+    QUERY_KW,
+    OBJECT_KW,
+    MODULE_KW,
+])
+.union(atom::STARTS_NULLARY);
+
+/// Whether a `{` at the start of an expression opens a block or an object.
+/// `grammar.mly` parameterises its expression rules with `bl` and `ob` for
+/// this. Only the leftmost operand of an expression inherits the mode, all
+/// others are in object mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum BraceMode {
+    /// `bl`, used in `exp_nest` positions like the body of an `if`, where a
+    /// `{` normally opens a block
+    Block,
+    /// `ob`, used everywhere else, including statements
+    Object,
+}
+
+/// exp
 pub(super) fn expr(p: &mut Parser) {
-    if atom::literal(p) {
+    if p.at(VAR_KW) {
+        var_decl(p)
+    } else {
+        expr_nonvar(p)
+    }
+}
+
+/// exp_nonvar
+fn expr_nonvar(p: &mut Parser) {
+    if at_decl_nonvar(p) {
+        decl_nonvar(p)
+    } else {
+        expr_nondec(p, BraceMode::Object)
+    }
+}
+
+/// exp_nest. A `{` opens a block here, unless it can only be a (deprecated)
+/// object, which may then continue like any other `bl` expression.
+fn expr_nest(p: &mut Parser) {
+    if !p.at(L_BRACE) {
+        expr(p)
+    } else if atom::definitely_object(p) {
+        expr_nondec(p, BraceMode::Block)
+    } else {
+        atom::block(p)
+    }
+}
+
+/// exp_nondec
+pub(super) fn expr_nondec(p: &mut Parser, mode: BraceMode) {
+    let c = p.checkpoint();
+    match p.current() {
+        RETURN_KW => {
+            p.bump(RETURN_KW);
+            if p.at_ts(STARTS_EXPR) {
+                expr(p);
+            }
+            p.finish_at(c, RETURN_EXPR)
+        }
+        ASYNC_KW => keyword_expr(p, ASYNC_KW, ASYNC_EXPR),
+        ASYNC_STAR_KW => keyword_expr(p, ASYNC_STAR_KW, ASYNC_EXPR),
+        AWAIT_KW => keyword_expr(p, AWAIT_KW, AWAIT_EXPR),
+        AWAIT_STAR_KW => keyword_expr(p, AWAIT_STAR_KW, AWAIT_EXPR),
+        ASSERT_KW => keyword_expr(p, ASSERT_KW, ASSERT_EXPR),
+        DEBUG_KW => keyword_expr(p, DEBUG_KW, DEBUG_EXPR),
+        IGNORE_KW => keyword_expr(p, IGNORE_KW, IGNORE_EXPR),
+        IF_KW => {
+            p.bump(IF_KW);
+            atom::expr_nullary(p, BraceMode::Object);
+            expr_nest(p);
+            // An `else` belongs to the innermost `if`, which `grammar.mly`
+            // expresses with the `IF_NO_ELSE` precedence
+            if p.eat(ELSE_KW) {
+                expr_nest(p);
+            }
+            p.finish_at(c, IF_EXPR)
+        }
+        SWITCH_KW => switch_expr(p),
+        TRY_KW => try_expr(p),
+        THROW_KW => {
+            p.bump(THROW_KW);
+            expr_nest(p);
+            p.finish_at(c, THROW_EXPR)
+        }
+        WHILE_KW => {
+            p.bump(WHILE_KW);
+            atom::expr_nullary(p, BraceMode::Object);
+            expr_nest(p);
+            p.finish_at(c, WHILE_EXPR)
+        }
+        LOOP_KW => {
+            p.bump(LOOP_KW);
+            expr_nest(p);
+            // Same as `else` above, see `LOOP_NO_WHILE`
+            if p.eat(WHILE_KW) {
+                expr_nest(p);
+            }
+            p.finish_at(c, LOOP_EXPR)
+        }
+        FOR_KW => for_expr(p),
+        DO_KW => {
+            p.bump(DO_KW);
+            if p.at(L_BRACE) {
+                atom::block(p)
+            } else {
+                p.error("Expected a {");
+            }
+            p.finish_at(c, DO_EXPR)
+        }
+        LABEL_KW => {
+            p.bump(LABEL_KW);
+            name(p);
+            opt_annot(p);
+            expr_nest(p);
+            p.finish_at(c, LABEL_EXPR)
+        }
+        BREAK_KW => {
+            p.bump(BREAK_KW);
+            name(p);
+            if p.at_ts(atom::STARTS_NULLARY) {
+                atom::expr_nullary(p, BraceMode::Object);
+            }
+            p.finish_at(c, BREAK_EXPR)
+        }
+        CONTINUE_KW => {
+            p.bump(CONTINUE_KW);
+            name(p);
+            p.finish_at(c, CONTINUE_EXPR)
+        }
+        _ => {
+            expr_bin(p, mode);
+            if let Some((op, n)) = current_op(p).filter(|(op, _)| ASSIGN_OPS.contains(*op)) {
+                p.bump_composite(op, n);
+                expr(p);
+                p.finish_at(c, ASSIGN_EXPR)
+            }
+        }
+    }
+}
+
+/// Parses `kw exp_nest`
+fn keyword_expr(p: &mut Parser, kw: SyntaxKind, kind: SyntaxKind) {
+    let c = p.checkpoint();
+    p.bump(kw);
+    expr_nest(p);
+    p.finish_at(c, kind)
+}
+
+fn switch_expr(p: &mut Parser) {
+    let c = p.checkpoint();
+    p.bump(SWITCH_KW);
+    atom::expr_nullary(p, BraceMode::Object);
+    let open = p.current_range();
+    if !p.eat(L_BRACE) {
+        p.error("Expected a {");
+        return p.finish_at(c, SWITCH_EXPR);
+    }
+    while p.at(CASE_KW) {
+        case_arm(p);
+        if !p.eat(SEMICOLON) {
+            break;
+        }
+    }
+    if !p.eat(R_BRACE) {
+        p.error_unclosed("Expected a }", open);
+    }
+    p.finish_at(c, SWITCH_EXPR)
+}
+
+/// case
+fn case_arm(p: &mut Parser) {
+    let c = p.checkpoint();
+    p.bump(CASE_KW);
+    if !patterns::pat_nullary(p) {
+        p.error("Expected a pattern");
+    }
+    expr_nest(p);
+    p.finish_at(c, CASE_ARM)
+}
+
+fn try_expr(p: &mut Parser) {
+    let c = p.checkpoint();
+    p.bump(TRY_KW);
+    expr_nest(p);
+    let catch = p.checkpoint();
+    if p.eat(CATCH_KW) {
+        if !patterns::pat_nullary(p) {
+            p.error("Expected a pattern");
+        }
+        expr_nest(p);
+        p.finish_at(catch, CATCH_CLAUSE)
+    } else {
+        p.error("Expected catch");
+    }
+    p.finish_at(c, TRY_EXPR)
+}
+
+fn for_expr(p: &mut Parser) {
+    let c = p.checkpoint();
+    p.bump(FOR_KW);
+    if !p.eat(L_PAREN) {
+        p.error("Expected a (");
+    }
+    patterns::pattern(p);
+    if !p.eat(IN_KW) {
+        p.error("Expected in");
+    }
+    expr(p);
+    if !p.eat(R_PAREN) {
+        p.error("Expected a )");
+    }
+    expr_nest(p);
+    p.finish_at(c, FOR_EXPR)
+}
+
+const ASSIGN_OPS: TokenSet = TokenSet::new(&[
+    COLON_EQUALS,
+    PLUS_EQUALS,
+    MINUS_EQUALS,
+    STAR_EQUALS,
+    SLASH_EQUALS,
+    PERCENT_EQUALS,
+    DOUBLE_STAR_EQUALS,
+    AMPERSAND_EQUALS,
+    PIPE_EQUALS,
+    CARET_EQUALS,
+    HASH_EQUALS,
+    SHL_EQUALS,
+    USHR_EQUALS,
+    SSHR_EQUALS,
+    ROTL_EQUALS,
+    ROTR_EQUALS,
+]);
+
+/// Operators the lexer splits into their single character parts, longest first.
+const COMPOSITE_OPS: &[(&[SyntaxKind], SyntaxKind)] = &[
+    (&[L_ANGLE, L_ANGLE, R_ANGLE, EQUALS], ROTL_EQUALS),
+    (&[L_ANGLE, R_ANGLE, R_ANGLE, EQUALS], ROTR_EQUALS),
+    (&[PLUS, R_ANGLE, R_ANGLE, EQUALS], SSHR_EQUALS),
+    (&[L_ANGLE, L_ANGLE, R_ANGLE], ROTL),
+    (&[L_ANGLE, R_ANGLE, R_ANGLE], ROTR),
+    (&[PLUS, R_ANGLE, R_ANGLE], SSHR),
+    (&[L_ANGLE, L_ANGLE, EQUALS], SHL_EQUALS),
+    (&[R_ANGLE, R_ANGLE, EQUALS], USHR_EQUALS),
+    (&[L_ANGLE, L_ANGLE], SHL),
+    (&[R_ANGLE, R_ANGLE], USHR),
+    (&[L_ANGLE, EQUALS], LESS_EQUALS),
+    (&[R_ANGLE, EQUALS], GREATER_EQUALS),
+];
+
+/// Returns the operator at the current position, and the number of tokens
+/// it is made up of.
+fn current_op(p: &Parser) -> Option<(SyntaxKind, usize)> {
+    if let Some(op) = composite_op(p) {
+        return Some(op);
+    }
+    match p.current() {
+        // `<` and `>` are only relational operators when surrounded by whitespace
+        L_ANGLE | R_ANGLE if !p.at_spaced() => None,
+        kind => Some((kind, 1)),
+    }
+}
+
+fn composite_op(p: &Parser) -> Option<(SyntaxKind, usize)> {
+    COMPOSITE_OPS
+        .iter()
+        .find(|(parts, _)| p.at_composite(parts))
+        .map(|(parts, op)| (*op, parts.len()))
+}
+
+/// The left binding power of a binary operator, and whether it is
+/// non-associative. Follows the precedence declarations in `grammar.mly`.
+/// Left associative operators bind one tighter on their right side.
+fn infix_binding_power(op: SyntaxKind) -> Option<(u8, bool)> {
+    let bp = match op {
+        PIPE_GREATER => (3, false),
+        OR_KW => (5, false),
+        AND_KW => (7, false),
+        DOUBLE_EQUALS | NOT_EQUALS | L_ANGLE | LESS_EQUALS | R_ANGLE | GREATER_EQUALS => (9, true),
+        PLUS | MINUS | HASH => (11, false),
+        STAR | SLASH | PERCENT => (13, false),
+        PIPE => (15, false),
+        AMPERSAND => (17, false),
+        CARET => (19, false),
+        SHL | USHR | SSHR | ROTL | ROTR => (21, true),
+        DOUBLE_STAR => (23, false),
+        _ => return None,
+    };
+    Some(bp)
+}
+
+/// `e : T` binds looser than any binary operator
+const ANNOT_BINDING_POWER: u8 = 1;
+
+/// exp_bin
+fn expr_bin(p: &mut Parser, mode: BraceMode) {
+    expr_bin_bp(p, 0, mode)
+}
+
+fn expr_bin_bp(p: &mut Parser, min_bp: u8, mode: BraceMode) {
+    let c = p.checkpoint();
+    expr_un(p, mode);
+    let mut last_nonassoc = None;
+    loop {
+        if p.at(COLON) {
+            if ANNOT_BINDING_POWER < min_bp {
+                break;
+            }
+            opt_annot_nobin(p);
+            p.finish_at(c, ANNOT_EXPR);
+            continue;
+        }
+        let (op, n) = match current_op(p) {
+            Some(op) => op,
+            None => break,
+        };
+        let (bp, nonassoc) = match infix_binding_power(op) {
+            Some(bp) if bp.0 >= min_bp => bp,
+            _ => break,
+        };
+        if nonassoc && last_nonassoc == Some(bp) {
+            p.error("Comparison and shift operators can't be chained, use parentheses");
+        }
+        last_nonassoc = if nonassoc { Some(bp) } else { None };
+        p.bump_composite(op, n);
+        expr_bin_bp(p, bp + 1, BraceMode::Object);
+        p.finish_at(c, BIN_EXPR);
+    }
+}
+
+/// exp_un
+fn expr_un(p: &mut Parser, mode: BraceMode) {
+    let c = p.checkpoint();
+    match p.current() {
+        HASH => {
+            p.bump(HASH);
+            if !p.eat(IDENT) {
+                p.error("Expected an ident");
+            }
+            p.finish_at(c, TAG);
+            if p.at_ts(atom::STARTS_NULLARY) {
+                atom::expr_nullary(p, BraceMode::Object);
+            }
+            p.finish_at(c, VARIANT_EXPR)
+        }
+        QUESTION => {
+            p.bump(QUESTION);
+            expr_un(p, BraceMode::Object);
+            p.finish_at(c, OPTION_EXPR)
+        }
+        PLUS | MINUS | CARET | NOT_KW | PLUS_EQUALS | MINUS_EQUALS | CARET_EQUALS => {
+            p.bump_any();
+            expr_un(p, BraceMode::Object);
+            p.finish_at(c, UNARY_EXPR)
+        }
+        ACTOR_KW => {
+            p.bump(ACTOR_KW);
+            atom::expr_plain(p);
+            p.finish_at(c, ACTOR_URL_EXPR)
+        }
+        DEBUG_SHOW_KW => {
+            p.bump(DEBUG_SHOW_KW);
+            expr_un(p, BraceMode::Object);
+            p.finish_at(c, SHOW_EXPR)
+        }
+        _ => expr_post(p, mode),
+    }
+}
+
+/// exp_post
+fn expr_post(p: &mut Parser, mode: BraceMode) {
+    let c = p.checkpoint();
+    if p.at(L_BRACKET) {
+        array_expr(p)
+    } else if !atom::expr_nullary(p, mode) {
         return;
     }
-    unreachable!("Can only do literals so far.")
+    loop {
+        match p.current() {
+            L_BRACKET => {
+                p.bump(L_BRACKET);
+                expr(p);
+                if !p.eat(R_BRACKET) {
+                    p.error("Expected a ]");
+                }
+                p.finish_at(c, INDEX_EXPR)
+            }
+            DOT_NUM => {
+                p.bump(DOT_NUM);
+                p.finish_at(c, PROJ_EXPR)
+            }
+            DOT => {
+                p.bump(DOT);
+                name(p);
+                p.finish_at(c, DOT_EXPR)
+            }
+            L_ANGLE if !p.at_spaced() && composite_op(p).is_none() => {
+                opt_typ_args(p);
+                atom::expr_nullary(p, BraceMode::Object);
+                p.finish_at(c, CALL_EXPR)
+            }
+            t if atom::STARTS_NULLARY.contains(t) => {
+                atom::expr_nullary(p, BraceMode::Object);
+                p.finish_at(c, CALL_EXPR)
+            }
+            _ => break,
+        }
+    }
+}
+
+fn array_expr(p: &mut Parser) {
+    assert!(p.at(L_BRACKET));
+    let c = p.checkpoint();
+    let open = p.current_range();
+    p.bump(L_BRACKET);
+    p.eat(VAR_KW);
+    while !p.at(R_BRACKET) && !p.at(EOF) {
+        expr_nonvar(p);
+        if !p.eat(COMMA) {
+            break;
+        }
+    }
+    if !p.eat(R_BRACKET) {
+        p.error_unclosed("Expected a ]", open);
+    }
+    p.finish_at(c, ARRAY_EXPR)
 }
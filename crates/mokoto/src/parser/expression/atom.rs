@@ -1,13 +1,250 @@
 use super::*;
 
-pub(crate) const LITERAL_FIRST: TokenSet = TokenSet::new(&[TRUE_KW, FALSE_KW, NUMBER_LIT]);
+/// Tokens that can follow an expression, so we don't skip them when an
+/// expression is missing
+const EXPR_RECOVERY: TokenSet = TokenSet::new(&[
+    SEMICOLON, COMMA, R_PAREN, R_BRACKET, LET_KW, VAR_KW, TYPE_KW, FUNC_KW, CLASS_KW, CASE_KW,
+    ELSE_KW, CATCH_KW,
+]);
 
-pub(crate) fn literal(p: &mut Parser) -> bool {
-    if !p.at_ts(LITERAL_FIRST) {
-        return false;
+pub(super) const STARTS_NULLARY: TokenSet =
+    TokenSet::new(&[L_PAREN, L_BRACE, IDENT, PRIM_KW]).union(STARTS_LIT);
+
+/// exp_nullary
+pub(super) fn expr_nullary(p: &mut Parser, mode: BraceMode) -> bool {
+    let c = p.checkpoint();
+    match p.current() {
+        L_PAREN => paren_or_tuple_expr(p),
+        L_BRACE => brace_expr(p, mode),
+        IDENT => {
+            p.bump(IDENT);
+            p.finish_at(c, NAME);
+            p.finish_at(c, VAR_EXPR)
+        }
+        PRIM_KW => {
+            p.bump(PRIM_KW);
+            literal(p);
+            p.finish_at(c, PRIM_EXPR)
+        }
+        t if STARTS_LIT.contains(t) => {
+            literal(p);
+            p.finish_at(c, LITERAL_EXPR)
+        }
+        _ => {
+            p.err_recover("Expected an expression", EXPR_RECOVERY);
+            return false;
+        }
+    }
+    true
+}
+
+/// exp_plain
+pub(super) fn expr_plain(p: &mut Parser) -> bool {
+    let c = p.checkpoint();
+    match p.current() {
+        L_PAREN => paren_or_tuple_expr(p),
+        t if STARTS_LIT.contains(t) => {
+            literal(p);
+            p.finish_at(c, LITERAL_EXPR)
+        }
+        _ => {
+            p.err_recover(
+                "Expected a literal or parenthesized expression",
+                EXPR_RECOVERY,
+            );
+            return false;
+        }
     }
-    p.start_node(LiteralE);
-    p.bump_any();
-    p.finish_node();
     true
 }
+
+fn paren_or_tuple_expr(p: &mut Parser) {
+    assert!(p.at(L_PAREN));
+    let c = p.checkpoint();
+    let open = p.current_range();
+    p.bump(L_PAREN);
+    if p.eat(R_PAREN) {
+        p.finish_at(c, TUPLE_EXPR);
+        return;
+    }
+    expr(p);
+    if p.eat(R_PAREN) {
+        p.finish_at(c, PAREN_EXPR);
+        return;
+    }
+    while p.eat(COMMA) && !p.at(R_PAREN) {
+        expr(p);
+    }
+    if !p.eat(R_PAREN) {
+        p.error_unclosed("Expected a )", open);
+    }
+    p.finish_at(c, TUPLE_EXPR)
+}
+
+/// A `{` either opens an object or a block. Both are accepted in either
+/// mode, but we warn about the deprecated one with `moc`'s message.
+fn brace_expr(p: &mut Parser, mode: BraceMode) {
+    let start = p.current_range().start();
+    match mode {
+        BraceMode::Object if maybe_object(p) => object_expr(p),
+        BraceMode::Object => {
+            block(p);
+            p.warning(
+                "block syntax is deprecated in this position, use 'do { ... }'",
+                p.range_from(start),
+            );
+        }
+        BraceMode::Block if definitely_object(p) => {
+            object_expr(p);
+            p.warning(
+                "object syntax is deprecated in this position, use '{ {...} }'",
+                p.range_from(start),
+            );
+        }
+        BraceMode::Block => block(p),
+    }
+}
+
+/// Whether the `{` we're at opens something that can only be an object,
+/// because it has a field like `x = 1` after any `var` fields
+pub(super) fn definitely_object(p: &Parser) -> bool {
+    at_field(p, skip_var_fields(p)) || has_with(p)
+}
+
+/// Whether the `{` we're at opens something that reads as an object, like
+/// `{}`, `{ var x = 1 }` or `{ var x = 1; y = 2 }`. Fields can't be punned,
+/// so `{ x }` and `{ x; y }` are blocks, and so is `{ var x = 1; f() }`.
+fn maybe_object(p: &Parser) -> bool {
+    p.nth_at(skip_var_fields(p), R_BRACE) || definitely_object(p)
+}
+
+/// Whether the lookahead at `n` is a field like `x = 1` or `x : Nat = 1`
+fn at_field(p: &Parser, n: usize) -> bool {
+    p.nth_at(n, IDENT) && matches!(p.nth(n + 1), EQUALS | COLON) && scan_item(p, n).1
+}
+
+/// Skips the `var` fields at the start of the braces we're at, and returns
+/// the lookahead position of the token after them
+fn skip_var_fields(p: &Parser) -> usize {
+    let mut n = 1;
+    while p.nth_at(n, VAR_KW) {
+        let (end, _) = scan_item(p, n);
+        if !p.nth_at(end, SEMICOLON) {
+            return end;
+        }
+        n = end + 1;
+    }
+    n
+}
+
+/// Scans the item at lookahead position `n` inside braces. Returns the
+/// position of the `;` or closing bracket that ends it, and whether it has a
+/// `=` that isn't nested in brackets.
+fn scan_item(p: &Parser, mut n: usize) -> (usize, bool) {
+    let mut depth = 0;
+    let mut equals = false;
+    loop {
+        match p.nth(n) {
+            L_BRACE | L_PAREN | L_BRACKET => depth += 1,
+            R_BRACE | R_PAREN | R_BRACKET if depth == 0 => return (n, equals),
+            R_BRACE | R_PAREN | R_BRACKET => depth -= 1,
+            SEMICOLON if depth == 0 => return (n, equals),
+            EQUALS if depth == 0 => equals = true,
+            EOF => return (n, equals),
+            _ => {}
+        }
+        n += 1;
+    }
+}
+
+/// Looks for a `with` that isn't nested inside the braces we're at
+fn has_with(p: &Parser) -> bool {
+    let mut depth = 0;
+    let mut n = 1;
+    loop {
+        match p.nth(n) {
+            L_BRACE | L_PAREN | L_BRACKET => depth += 1,
+            R_BRACE | R_PAREN | R_BRACKET if depth == 0 => return false,
+            R_BRACE | R_PAREN | R_BRACKET => depth -= 1,
+            WITH_KW if depth == 0 => return true,
+            EOF => return false,
+            _ => {}
+        }
+        n += 1;
+    }
+}
+
+/// exp_obj
+fn object_expr(p: &mut Parser) {
+    assert!(p.at(L_BRACE));
+    let c = p.checkpoint();
+    let open = p.current_range();
+    let has_bases = has_with(p);
+    p.bump(L_BRACE);
+    if has_bases {
+        expr_post(p, BraceMode::Object);
+        while p.eat(AND_KW) {
+            expr_post(p, BraceMode::Object);
+        }
+        if !p.eat(WITH_KW) {
+            p.error("Expected with");
+        }
+    }
+    while !p.at(R_BRACE) && !p.at(EOF) {
+        if p.at(L_BRACE) {
+            // Recovery stops at braces, so skip the whole block instead
+            p.error("Expected a field");
+            p.start_node(ERROR);
+            block(p);
+            p.finish_node();
+            continue;
+        }
+        exp_field(p);
+        if p.eat(SEMICOLON) || p.at(R_BRACE) {
+            continue;
+        }
+        p.error("Expected a ;");
+        p.skip_until(FIELD_RECOVERY);
+        p.eat(SEMICOLON);
+    }
+    if !p.eat(R_BRACE) {
+        p.error_unclosed("Expected a }", open);
+    }
+    p.finish_at(c, OBJECT_EXPR)
+}
+
+/// Tokens that can start a field, so we don't skip them when recovering
+const FIELD_RECOVERY: TokenSet = TokenSet::new(&[SEMICOLON, VAR_KW, IDENT]);
+
+/// exp_field
+fn exp_field(p: &mut Parser) {
+    let c = p.checkpoint();
+    p.eat(VAR_KW);
+    name(p);
+    opt_annot(p);
+    if !p.eat(EQUALS) {
+        p.error("Expected an =");
+    }
+    expr(p);
+    p.finish_at(c, EXP_FIELD)
+}
+
+pub(crate) fn block(p: &mut Parser) {
+    assert!(p.at(L_BRACE));
+    let c = p.checkpoint();
+    let open = p.current_range();
+    p.bump(L_BRACE);
+    while !p.at(R_BRACE) && !p.at(EOF) {
+        decl(p);
+        if p.eat(SEMICOLON) || p.at(R_BRACE) {
+            continue;
+        }
+        p.error("Expected a ;");
+        p.skip_until(DECL_RECOVERY);
+        p.eat(SEMICOLON);
+    }
+    if !p.eat(R_BRACE) {
+        p.error_unclosed("Expected a }", open);
+    }
+    p.finish_at(c, BLOCK_EXPR)
+}
@@ -1,8 +1,9 @@
 use super::{Parser, TokenSet};
 use crate::lexer::SyntaxKind::*;
 
-// TODO: All literals
-pub(crate) const STARTS_LIT: TokenSet = TokenSet::new(&[NULL_KW, TRUE_KW, FALSE_KW, NUMBER_LIT]);
+pub(crate) const STARTS_LIT: TokenSet = TokenSet::new(&[
+    NULL_KW, TRUE_KW, FALSE_KW, NAT_LIT, FLOAT_LIT, TEXT_LIT, CHAR_LIT,
+]);
 
 pub fn literal(p: &mut Parser) {
     let c = p.checkpoint();
@@ -10,7 +11,6 @@ pub fn literal(p: &mut Parser) {
         p.bump_any();
         p.finish_at(c, LITERAL)
     } else {
-        // TODO: Error
-        unreachable!()
+        p.error("Expected a literal")
     }
 }
@@ -1,15 +1,38 @@
 use super::literals::{literal, STARTS_LIT};
 use super::types::opt_annot;
-use super::Parser;
+use super::{Parser, TokenSet};
 use crate::lexer::SyntaxKind::*;
 
+/// Tokens that can follow a pattern, so we don't skip them when a pattern is
+/// missing
+const PAT_RECOVERY: TokenSet =
+    TokenSet::new(&[COMMA, R_PAREN, SEMICOLON, EQUALS, COLON, IN_KW, OR_KW]);
+
+/// pat_bin. Both `or` and `:` are left associative, with `:` binding looser,
+/// so we can build the tree from left to right.
 pub(super) fn pattern(p: &mut Parser) {
-    pat_un(p)
+    let c = p.checkpoint();
+    pat_un(p);
+    loop {
+        match p.current() {
+            OR_KW => {
+                p.bump(OR_KW);
+                pat_un(p);
+                p.finish_at(c, ALT_PAT)
+            }
+            COLON => {
+                opt_annot(p);
+                p.finish_at(c, ANNOT_PAT)
+            }
+            _ => break,
+        }
+    }
 }
 
 fn paren_or_tuple_pattern(p: &mut Parser) {
     assert!(p.at(L_PAREN));
     let c = p.checkpoint();
+    let open = p.current_range();
     p.bump(L_PAREN);
     if p.eat(R_PAREN) {
         p.finish_at(c, TUPLE_PAT);
@@ -23,8 +46,9 @@ fn paren_or_tuple_pattern(p: &mut Parser) {
     while p.eat(COMMA) {
         pattern(p);
     }
-    // TODO Error
-    p.bump(R_PAREN);
+    if !p.eat(R_PAREN) {
+        p.error_unclosed("Expected a )", open);
+    }
     p.finish_at(c, TUPLE_PAT)
 }
 
@@ -50,25 +74,34 @@ fn pat_un(p: &mut Parser) {
         HASH => {
             p.bump(HASH);
             if !p.eat(IDENT) {
-                // TODO: Error
-                unreachable!()
+                p.error("Expected an ident");
             }
             p.finish_at(c, TAG);
             let _ = pat_nullary(p);
             p.finish_at(c, VARIANT_PAT)
         }
+        QUESTION => {
+            p.bump(QUESTION);
+            pat_un(p);
+            p.finish_at(c, OPTION_PAT)
+        }
+        PLUS | MINUS | CARET => {
+            p.bump_any();
+            literal(p);
+            p.finish_at(c, LITERAL_PAT)
+        }
         _ => {
             if !pat_nullary(p) {
-                // TODO: Error
-                unreachable!()
+                p.err_recover("Expected a pattern", PAT_RECOVERY)
             }
         }
     }
 }
 
-fn pat_nullary(p: &mut Parser) -> bool {
+pub(super) fn pat_nullary(p: &mut Parser) -> bool {
     if p.at(L_BRACE) {
         let c = p.checkpoint();
+        let open = p.current_range();
         p.bump(L_BRACE);
         while pat_field(p) {
             if !p.eat(SEMICOLON) {
@@ -76,7 +109,7 @@ fn pat_nullary(p: &mut Parser) -> bool {
             }
         }
         if !p.eat(R_BRACE) {
-            unreachable!()
+            p.error_unclosed("Expected a }", open);
         }
         p.finish_at(c, OBJECT_PAT);
         true
@@ -85,7 +118,7 @@ fn pat_nullary(p: &mut Parser) -> bool {
     }
 }
 
-fn pat_plain(p: &mut Parser) -> bool {
+pub(super) fn pat_plain(p: &mut Parser) -> bool {
     let c = p.checkpoint();
     match p.current() {
         UNDERSCORE => {
@@ -102,7 +135,6 @@ fn pat_plain(p: &mut Parser) -> bool {
             literal(p);
             p.finish_at(c, LITERAL_PAT)
         }
-        // TODO: Error
         _ => return false,
     };
     true
@@ -7,8 +7,6 @@ use crate::lexer::SyntaxKind;
 pub(crate) struct TokenSet(u128);
 
 impl TokenSet {
-    pub(crate) const EMPTY: TokenSet = TokenSet(0);
-
     pub(crate) const fn new(kinds: &[SyntaxKind]) -> TokenSet {
         let mut res = 0u128;
         let mut i = 0;
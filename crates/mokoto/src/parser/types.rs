@@ -1,15 +1,8 @@
+use super::declaration::name;
+use super::literals::literal;
 use super::*;
 
-fn opt_mutability(p: &mut Parser) -> bool {
-    let c = p.checkpoint();
-    if p.eat(VAR_KW) {
-        p.finish_at(c, MutModifier);
-        return true;
-    }
-    false
-}
-
-fn opt_typ_params(p: &mut Parser) -> bool {
+pub(super) fn opt_typ_params(p: &mut Parser) -> bool {
     let c = p.checkpoint();
     if p.eat(L_ANGLE) {
         typ_bind(p);
@@ -17,7 +10,7 @@ fn opt_typ_params(p: &mut Parser) -> bool {
             typ_bind(p);
         }
         if !p.eat(R_ANGLE) {
-            // TODO Error
+            p.error("Expected a >");
         }
         p.finish_at(c, TYPE_PARAMS);
         return true;
@@ -25,7 +18,7 @@ fn opt_typ_params(p: &mut Parser) -> bool {
     false
 }
 
-fn opt_typ_args(p: &mut Parser) -> bool {
+pub(super) fn opt_typ_args(p: &mut Parser) -> bool {
     let c = p.checkpoint();
     if p.eat(L_ANGLE) {
         typ(p);
@@ -33,7 +26,7 @@ fn opt_typ_args(p: &mut Parser) -> bool {
             typ(p);
         }
         if !p.eat(R_ANGLE) {
-            // TODO Error
+            p.error("Expected a >");
         }
         p.finish_at(c, TYPE_ARGS);
         return true;
@@ -46,8 +39,10 @@ fn path(p: &mut Parser) {
     let c = p.checkpoint();
     p.bump(IDENT);
     while p.eat(DOT) {
-        // TODO: Error
-        p.bump(IDENT);
+        if !p.eat(IDENT) {
+            p.error("Expected an ident");
+            break;
+        }
     }
     p.finish_at(c, PATH)
 }
@@ -56,9 +51,11 @@ fn array_typ(p: &mut Parser) {
     assert!(p.at(L_BRACKET));
     let c = p.checkpoint();
     p.bump(L_BRACKET);
-    opt_mutability(p);
+    p.eat(VAR_KW);
     typ(p);
-    p.bump(R_BRACKET);
+    if !p.eat(R_BRACKET) {
+        p.error("Expected a ]");
+    }
     p.finish_at(c, ARRAY_TYPE)
 }
 
@@ -79,6 +76,7 @@ fn opt_func_sort(p: &mut Parser) -> bool {
 fn paren_or_tuple_typ(p: &mut Parser) {
     assert!(p.at(L_PAREN));
     let c = p.checkpoint();
+    let open = p.current_range();
     p.bump(L_PAREN);
     if p.eat(R_PAREN) {
         p.finish_at(c, TUPLE_TYPE);
@@ -92,8 +90,9 @@ fn paren_or_tuple_typ(p: &mut Parser) {
     while p.eat(COMMA) {
         typ_item(p);
     }
-    // TODO Error
-    p.bump(R_PAREN);
+    if !p.eat(R_PAREN) {
+        p.error_unclosed("Expected a )", open);
+    }
     p.finish_at(c, TUPLE_TYPE)
 }
 
@@ -105,56 +104,84 @@ pub(crate) fn opt_annot(p: &mut Parser) {
     }
 }
 
+/// The annotation of an expression, see [typ_nobin]
+pub(crate) fn opt_annot_nobin(p: &mut Parser) {
+    let c = p.checkpoint();
+    if p.eat(COLON) {
+        typ_nobin(p);
+        p.finish_at(c, TYPE_ANNOTATION)
+    }
+}
+
 fn typ_tag(p: &mut Parser) {
     let c = p.checkpoint();
     if !p.eat(HASH) {
-        // TODO: error
-    }
-    if !p.eat(IDENT) {
-        // TODO: error
+        p.error("Expected a #");
     }
+    name(p);
     opt_annot(p);
     p.finish_at(c, TYPE_TAG);
 }
 
 fn typ_field(p: &mut Parser) {
     let c = p.checkpoint();
-    if opt_mutability(p) || (p.at(IDENT) && p.nth_at(1, COLON)) {
-        p.bump(IDENT);
-        p.bump(COLON);
-        typ(p);
+    if p.eat(VAR_KW) || (p.at(IDENT) && p.nth_at(1, COLON)) {
+        name(p);
+        if p.at(COLON) {
+            opt_annot(p)
+        } else {
+            p.error("Expected a :");
+        }
         p.finish_at(c, TYPE_FIELD);
     } else {
-        p.bump(IDENT);
+        name(p);
         opt_typ_params(p);
+        let arg = p.checkpoint();
         typ_nullary(p);
-        p.bump(COLON);
+        p.finish_at(arg, FUNC_ARG);
+        if !p.eat(COLON) {
+            p.error("Expected a :");
+        }
+        let result = p.checkpoint();
         typ(p);
+        p.finish_at(result, FUNC_RESULT);
         p.finish_at(c, TYPE_FIELD_FUNC);
     }
 }
 
 /// Does not create its own Node (so the level above can include object sorts)
 fn typ_obj(p: &mut Parser) {
+    let open = p.current_range();
     if !p.eat(L_BRACE) {
-        // TODO Error
-        unreachable!("typ_obj")
+        p.error("Expected a {");
+        return;
     }
     while p.at(IDENT) || p.at(VAR_KW) {
         typ_field(p);
-        p.eat(COMMA);
+        if !field_separator(p) {
+            break;
+        }
     }
     if !p.eat(R_BRACE) {
-        // TODO: error
+        p.error_unclosed("Expected a }", open);
+    }
+}
+
+/// Fields of object and variant types are separated by `;`. A `,` is
+/// reported and skipped, so we can recover and parse the next field.
+fn field_separator(p: &mut Parser) -> bool {
+    if p.at(COMMA) {
+        p.err_and_bump("Expected a ;");
+        true
+    } else {
+        p.eat(SEMICOLON)
     }
 }
 
 fn typ_variant(p: &mut Parser) {
     let c = p.checkpoint();
-    if !p.eat(L_BRACE) {
-        // TODO Error
-        unreachable!("typ_variant")
-    }
+    let open = p.current_range();
+    p.bump(L_BRACE);
     if p.at(HASH) && p.nth_at(1, R_BRACE) {
         p.bump(HASH);
         p.bump(R_BRACE);
@@ -162,21 +189,23 @@ fn typ_variant(p: &mut Parser) {
     }
     while p.at(HASH) {
         typ_tag(p);
-        p.eat(COMMA);
+        if !field_separator(p) {
+            break;
+        }
     }
     if !p.eat(R_BRACE) {
-        // TODO: error
+        p.error_unclosed("Expected a }", open);
     }
     p.finish_at(c, VARIANT_TYPE)
 }
 
 fn typ_bind(p: &mut Parser) {
     let c = p.checkpoint();
-    if !p.eat(IDENT) {
-        // TODO: Error
-    }
+    name(p);
+    let bound = p.checkpoint();
     if p.eat(SUB) {
-        typ(p)
+        typ(p);
+        p.finish_at(bound, TYPE_BOUND)
     }
     p.finish_at(c, TYPE_BIND)
 }
@@ -189,16 +218,6 @@ fn typ_item(p: &mut Parser) {
         p.bump(COLON);
         typ(p);
         p.finish_at(c, NAMED_TYPE);
-    } else if p.at(IDENT) {
-        typ_nullary(p);
-        if p.at(ARROW) {
-            p.finish_at(c, FUNC_ARG);
-            p.bump(ARROW);
-            let c1 = p.checkpoint();
-            typ(p);
-            p.finish_at(c1, FUNC_RESULT);
-            p.finish_at(c, FUNC_TYPE);
-        }
     } else {
         typ(p);
     }
@@ -208,6 +227,12 @@ fn typ_nullary(p: &mut Parser) {
     match p.current() {
         L_PAREN => paren_or_tuple_typ(p),
         L_BRACKET => array_typ(p),
+        IDENT if !p.nth_at(1, DOT) && !p.nth_at(1, L_ANGLE) && prim_ident(p).is_some() => {
+            let kind = prim_ident(p).unwrap();
+            let c = p.checkpoint();
+            p.bump(IDENT);
+            p.finish_at(c, kind)
+        }
         IDENT => {
             let c = p.checkpoint();
             path(p);
@@ -223,8 +248,18 @@ fn typ_nullary(p: &mut Parser) {
                 p.finish_at(c, OBJECT_TYPE)
             }
         }
-        // TODO: Error
-        _ => unreachable!("Unexpected token in typ_nullary"),
+        _ => p.err_recover("Expected a type", TYPE_RECOVERY),
+    }
+}
+
+/// `Any`, `None` and `Null` are identifiers, but on their own they name the
+/// top, bottom and null types
+fn prim_ident(p: &Parser) -> Option<SyntaxKind> {
+    match p.current_text() {
+        "Any" => Some(ANY_TYPE),
+        "None" => Some(NONE_TYPE),
+        "Null" => Some(NULL_TYPE),
+        _ => None,
     }
 }
 
@@ -240,9 +275,9 @@ fn typ_un(p: &mut Parser) {
 
 fn typ_pre(p: &mut Parser) {
     match p.current() {
-        ASYNC_KW => {
+        ASYNC_KW | ASYNC_STAR_KW => {
             let c = p.checkpoint();
-            p.bump(ASYNC_KW);
+            p.bump_any();
             typ_pre(p);
             p.finish_at(c, ASYNC_TYPE)
         }
@@ -250,9 +285,14 @@ fn typ_pre(p: &mut Parser) {
         PRIM_KW => {
             let c = p.checkpoint();
             p.bump(PRIM_KW);
-            p.bump(IDENT);
+            if p.at(TEXT_LIT) {
+                literal(p)
+            } else {
+                p.error("Expected a text literal");
+            }
             p.finish_at(c, PRIM_TYPE)
         }
+        ACTOR_KW if p.nth_at(1, CLASS_KW) => actor_class_typ(p),
         OBJECT_KW | ACTOR_KW | MODULE_KW => {
             let c = p.checkpoint();
             p.bump_any();
@@ -264,19 +304,79 @@ fn typ_pre(p: &mut Parser) {
     }
 }
 
-const STARTS_PRE: TokenSet = TokenSet::new(&[ASYNC_KW, OBJECT_KW, ACTOR_KW, MODULE_KW, PRIM_KW]);
+/// The type of an actor class, a function from its arguments to the actor
+fn actor_class_typ(p: &mut Parser) {
+    let c = p.checkpoint();
+    p.bump(ACTOR_KW);
+    p.bump(CLASS_KW);
+    opt_typ_params(p);
+    let arg = p.checkpoint();
+    typ_un(p);
+    p.finish_at(arg, FUNC_ARG);
+    if !p.eat(ARROW) {
+        p.error("Expected a ->");
+    }
+    let result = p.checkpoint();
+    typ_nobin(p);
+    p.finish_at(result, FUNC_RESULT);
+    p.finish_at(c, ACTOR_CLASS_TYPE)
+}
+
+/// Tokens that can follow a type, so we don't skip them when a type is missing
+const TYPE_RECOVERY: TokenSet = TokenSet::new(&[
+    COMMA, R_PAREN, R_BRACKET, R_ANGLE, SEMICOLON, EQUALS, ARROW, AND_KW, OR_KW,
+]);
+
+const STARTS_PRE: TokenSet = TokenSet::new(&[
+    ASYNC_KW,
+    ASYNC_STAR_KW,
+    OBJECT_KW,
+    ACTOR_KW,
+    MODULE_KW,
+    PRIM_KW,
+]);
 
 pub(super) fn typ(p: &mut Parser) {
+    typ_bin(p, 0)
+}
+
+/// `and` binds tighter than `or`, both are left associative
+fn typ_binding_power(op: SyntaxKind) -> Option<(u8, SyntaxKind)> {
+    match op {
+        OR_KW => Some((1, OR_TYPE)),
+        AND_KW => Some((2, AND_TYPE)),
+        _ => None,
+    }
+}
+
+fn typ_bin(p: &mut Parser, min_bp: u8) {
+    let c = p.checkpoint();
+    typ_nobin(p);
+    while let Some((bp, kind)) = typ_binding_power(p.current()) {
+        if bp < min_bp {
+            break;
+        }
+        p.bump_any();
+        typ_bin(p, bp + 1);
+        p.finish_at(c, kind);
+    }
+}
+
+/// typ_nobin. Type annotations on expressions stop here, so the `and` and
+/// `or` operators aren't mistaken for intersections and unions.
+pub(super) fn typ_nobin(p: &mut Parser) {
     let c = p.checkpoint();
     let fs = opt_func_sort(p);
     let tp = opt_typ_params(p);
     if fs || tp {
+        let arg = p.checkpoint();
         typ_un(p);
-        p.finish_at(c, FUNC_ARG);
-        // TODO: Error
-        p.bump(ARROW);
+        p.finish_at(arg, FUNC_ARG);
+        if !p.eat(ARROW) {
+            p.error("Expected a ->");
+        }
         let c1 = p.checkpoint();
-        typ(p);
+        typ_nobin(p);
         p.finish_at(c1, FUNC_RESULT);
         p.finish_at(c, FUNC_TYPE);
     } else if STARTS_PRE.contains(p.current()) {
@@ -285,10 +385,9 @@ pub(super) fn typ(p: &mut Parser) {
         typ_un(p);
         if p.at(ARROW) {
             p.finish_at(c, FUNC_ARG);
-            // TODO: Error
             p.bump(ARROW);
             let c1 = p.checkpoint();
-            typ(p);
+            typ_nobin(p);
             p.finish_at(c1, FUNC_RESULT);
             p.finish_at(c, FUNC_TYPE);
         }
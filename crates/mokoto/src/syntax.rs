@@ -1,10 +1,17 @@
 use crate::lexer::SyntaxKind;
-use crate::syntax::nodes::{Pattern, PatternField};
+use crate::syntax::ast::{support, AstNode, AstToken, HasName, HasVisibility};
+use crate::syntax::nodes::{Expr, Pattern, PatternField, Type};
+use crate::syntax::number::{parse_float, parse_nat, BigUint, NumberError};
+use crate::syntax::unescape::{unescape_char, unescape_text, EscapeError};
 use num_traits::{FromPrimitive, ToPrimitive};
 use rowan::SmolStr;
 
 pub mod ast;
+pub mod make;
 pub mod nodes;
+pub mod number;
+pub mod tokens;
+pub mod unescape;
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub enum MotokoLanguage {}
@@ -29,26 +36,168 @@ impl rowan::Language for MotokoLanguage {
 
 impl nodes::Path {
     pub fn segments(&self) -> Vec<String> {
-        let idents = self.syntax.children_with_tokens().filter_map(|n| {
-            n.as_token().and_then(|t| {
-                if t.kind() == crate::T![ident] {
-                    Some(t.text().to_string())
-                } else {
-                    None
-                }
-            })
-        });
-        idents.collect()
+        let idents = self
+            .syntax
+            .children_with_tokens()
+            .filter_map(|n| n.into_token().and_then(tokens::Ident::cast));
+        idents.map(|ident| ident.text().to_string()).collect()
+    }
+}
+
+impl nodes::Literal {
+    pub fn token(&self) -> Option<SyntaxToken> {
+        first_token(self.syntax())
+    }
+
+    /// The value of a natural number literal, or `None` if this literal isn't
+    /// a natural number
+    pub fn nat_value(&self) -> Option<Result<BigUint, NumberError>> {
+        Some(self.nat_lit()?.value())
+    }
+
+    /// The value of a float literal, or `None` if this literal isn't a float
+    pub fn float_value(&self) -> Option<Result<f64, NumberError>> {
+        Some(self.float_lit()?.value())
+    }
+
+    /// The decoded value of a text literal, or `None` if this literal isn't
+    /// text
+    pub fn text_value(&self) -> Option<Result<String, Vec<EscapeError>>> {
+        Some(self.text_lit()?.value())
+    }
+
+    /// The decoded value of a character literal, or `None` if this literal
+    /// isn't a character
+    pub fn char_value(&self) -> Option<Result<char, Vec<EscapeError>>> {
+        Some(self.char_lit()?.value())
+    }
+}
+
+impl tokens::Ident {
+    /// The name this identifier refers to. Unlike Rust's `r#` identifiers,
+    /// Motoko identifiers can't be escaped, so this is the text of the token.
+    pub fn text_without_escape(&self) -> &str {
+        self.text().as_str()
+    }
+}
+
+impl tokens::NatLit {
+    /// Malformed literals are reported with their range in the source
+    pub fn value(&self) -> Result<BigUint, NumberError> {
+        parse_nat(self.text()).map_err(|error| number_in_source(self.syntax(), error))
+    }
+}
+
+impl tokens::FloatLit {
+    /// Malformed literals are reported with their range in the source
+    pub fn value(&self) -> Result<f64, NumberError> {
+        parse_float(self.text()).map_err(|error| number_in_source(self.syntax(), error))
+    }
+}
+
+impl tokens::TextLit {
+    /// The decoded text. Malformed escapes are reported with their range in
+    /// the source.
+    pub fn value(&self) -> Result<String, Vec<EscapeError>> {
+        unescape_text(self.text()).map_err(|errors| in_source(self.syntax(), errors))
+    }
+}
+
+impl tokens::CharLit {
+    /// The decoded character. Malformed escapes are reported with their range
+    /// in the source.
+    pub fn value(&self) -> Result<char, Vec<EscapeError>> {
+        unescape_char(self.text()).map_err(|errors| in_source(self.syntax(), errors))
+    }
+}
+
+impl tokens::Comment {
+    /// Doc comments start with `///` or `/**`
+    pub fn is_doc(&self) -> bool {
+        matches!(
+            self.syntax().kind(),
+            SyntaxKind::LINE_DOC_COMMENT | SyntaxKind::BLOCK_DOC_COMMENT
+        )
+    }
+
+    pub fn is_block(&self) -> bool {
+        matches!(
+            self.syntax().kind(),
+            SyntaxKind::BLOCK_COMMENT | SyntaxKind::BLOCK_DOC_COMMENT
+        )
+    }
+}
+
+impl tokens::Whitespace {
+    /// Whether the whitespace around this token contains a blank line. Every
+    /// line break is a token of its own, so this looks at the whole run of
+    /// whitespace tokens this one is part of.
+    pub fn spans_multiple_lines(&self) -> bool {
+        let mut start = self.syntax().clone();
+        while let Some(prev) = start
+            .prev_token()
+            .filter(|t| tokens::Whitespace::can_cast(t.kind()))
+        {
+            start = prev;
+        }
+        let line_breaks = std::iter::successors(Some(start), |t| t.next_token())
+            .take_while(|t| tokens::Whitespace::can_cast(t.kind()))
+            .filter(|t| t.kind() == SyntaxKind::LINEFEED)
+            .count();
+        line_breaks > 1
+    }
+}
+
+/// Moves escape errors from literal relative ranges to ranges in the source
+fn in_source(token: &SyntaxToken, mut errors: Vec<EscapeError>) -> Vec<EscapeError> {
+    let start = token.text_range().start();
+    for error in &mut errors {
+        error.range += start;
+    }
+    errors
+}
+
+fn number_in_source(token: &SyntaxToken, mut error: NumberError) -> NumberError {
+    error.range += token.text_range().start();
+    error
+}
+
+impl nodes::ImportDecl {
+    /// The decoded path being imported, like `mo:base/Array`, or `None` if
+    /// it is missing
+    pub fn path(&self) -> Option<Result<String, Vec<EscapeError>>> {
+        self.literal()?.text_value()
+    }
+}
+
+impl nodes::DeclField {
+    /// Fields are private unless declared otherwise
+    pub fn is_public(&self) -> bool {
+        self.visibility()
+            .is_some_and(|v| v.public_token().is_some())
+    }
+
+    /// Whether this is one of the `system` functions like `preupgrade`
+    pub fn is_system(&self) -> bool {
+        self.visibility()
+            .is_some_and(|v| v.system_token().is_some())
+    }
+
+    /// Fields are flexible unless declared otherwise
+    pub fn is_stable(&self) -> bool {
+        self.stability().is_some_and(|s| s.stable_token().is_some())
     }
 }
 
 impl nodes::Pattern {
+    /// The names bound by this pattern. Parts that are missing because of
+    /// syntax errors don't bind anything.
     pub fn idents(&self) -> Vec<nodes::Name> {
         match self {
             Pattern::WildcardPat(_) => vec![],
-            Pattern::VarPat(n) => vec![n.name().unwrap()],
+            Pattern::VarPat(n) => n.name().into_iter().collect(),
             Pattern::LiteralPat(_) => vec![],
-            Pattern::ParenPat(p) => p.pattern().unwrap().idents(),
+            Pattern::ParenPat(p) => p.pattern().map_or(vec![], |p| p.idents()),
             Pattern::TuplePat(p) => {
                 let mut res = vec![];
                 for p in p.patterns() {
@@ -60,16 +209,268 @@ impl nodes::Pattern {
                 let mut res = vec![];
                 for field in p.fields() {
                     let mut names = match field {
-                        PatternField::PatternFieldPun(p) => vec![p.name().unwrap()],
-                        PatternField::PatternFieldPat(p) => p.pattern().unwrap().idents(),
+                        PatternField::PatternFieldPun(p) => p.name().into_iter().collect(),
+                        PatternField::PatternFieldPat(p) => {
+                            p.pattern().map_or(vec![], |p| p.idents())
+                        }
                     };
                     res.append(&mut names);
                 }
                 res
             }
-            Pattern::VariantPat(p) => {
-                p.pattern().map_or(vec![], |p| p.idents())
+            Pattern::VariantPat(p) => p.pattern().map_or(vec![], |p| p.idents()),
+            Pattern::OptionPat(p) => p.pattern().map_or(vec![], |p| p.idents()),
+            // Both branches should bind the same names, see `AltPat::mismatched_idents`.
+            // If they don't we still return every name that is bound.
+            Pattern::AltPat(p) => {
+                let mut res = p.lhs().map_or(vec![], |p| p.idents());
+                for name in p.rhs().map_or(vec![], |p| p.idents()) {
+                    if !contains_ident(&res, &name) {
+                        res.push(name)
+                    }
+                }
+                res
+            }
+            Pattern::AnnotPat(p) => p.pattern().map_or(vec![], |p| p.idents()),
+        }
+    }
+}
+
+fn ident_text(name: &nodes::Name) -> Option<SmolStr> {
+    name.ident().map(|ident| ident.text().clone())
+}
+
+fn contains_ident(names: &[nodes::Name], name: &nodes::Name) -> bool {
+    names.iter().any(|n| ident_text(n) == ident_text(name))
+}
+
+impl nodes::LiteralPat {
+    /// The sign of a signed literal pattern like `-1`
+    pub fn op_token(&self) -> Option<SyntaxToken> {
+        first_token(self.syntax())
+    }
+}
+
+impl nodes::AltPat {
+    pub fn lhs(&self) -> Option<Pattern> {
+        support::children(self.syntax()).next()
+    }
+
+    pub fn rhs(&self) -> Option<Pattern> {
+        support::children(self.syntax()).nth(1)
+    }
+
+    /// The names that are only bound by one of the branches. Both branches of
+    /// an `or` pattern must bind the same names, so this should be empty.
+    pub fn mismatched_idents(&self) -> Vec<nodes::Name> {
+        let lhs = self.lhs().map_or(vec![], |p| p.idents());
+        let rhs = self.rhs().map_or(vec![], |p| p.idents());
+        let only_lhs = lhs.iter().filter(|name| !contains_ident(&rhs, name));
+        let only_rhs = rhs.iter().filter(|name| !contains_ident(&lhs, name));
+        only_lhs.chain(only_rhs).cloned().collect()
+    }
+}
+
+impl nodes::IndexExpr {
+    pub fn base(&self) -> Option<Expr> {
+        support::children(self.syntax()).next()
+    }
+
+    pub fn index(&self) -> Option<Expr> {
+        support::children(self.syntax()).nth(1)
+    }
+}
+
+impl nodes::ProjExpr {
+    /// The index of the projected tuple component, `1` for `t.1`
+    pub fn index(&self) -> Option<usize> {
+        let token = self.dot_num_token()?;
+        token.text()[1..].replace('_', "").parse().ok()
+    }
+}
+
+impl nodes::CallExpr {
+    pub fn arg(&self) -> Option<Expr> {
+        support::children(self.syntax()).nth(1)
+    }
+}
+
+/// The first non-trivia token that is a direct child of `node`
+fn first_token(node: &SyntaxNode) -> Option<SyntaxToken> {
+    node.children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .find(|it| !it.kind().is_trivia())
+}
+
+impl nodes::UnaryExpr {
+    pub fn op_token(&self) -> Option<SyntaxToken> {
+        first_token(self.syntax())
+    }
+}
+
+impl nodes::AndType {
+    pub fn lhs(&self) -> Option<Type> {
+        support::children(self.syntax()).next()
+    }
+
+    pub fn rhs(&self) -> Option<Type> {
+        support::children(self.syntax()).nth(1)
+    }
+}
+
+impl nodes::OrType {
+    pub fn lhs(&self) -> Option<Type> {
+        support::children(self.syntax()).next()
+    }
+
+    pub fn rhs(&self) -> Option<Type> {
+        support::children(self.syntax()).nth(1)
+    }
+}
+
+impl nodes::BinExpr {
+    pub fn lhs(&self) -> Option<Expr> {
+        support::children(self.syntax()).next()
+    }
+
+    pub fn op_token(&self) -> Option<SyntaxToken> {
+        first_token(self.syntax())
+    }
+
+    pub fn rhs(&self) -> Option<Expr> {
+        support::children(self.syntax()).nth(1)
+    }
+}
+
+impl nodes::AssignExpr {
+    pub fn lhs(&self) -> Option<Expr> {
+        support::children(self.syntax()).next()
+    }
+
+    pub fn op_token(&self) -> Option<SyntaxToken> {
+        first_token(self.syntax())
+    }
+
+    pub fn rhs(&self) -> Option<Expr> {
+        support::children(self.syntax()).nth(1)
+    }
+}
+
+impl nodes::IfExpr {
+    pub fn condition(&self) -> Option<Expr> {
+        support::children(self.syntax()).next()
+    }
+
+    pub fn then_branch(&self) -> Option<Expr> {
+        support::children(self.syntax()).nth(1)
+    }
+
+    pub fn else_branch(&self) -> Option<Expr> {
+        support::children(self.syntax()).nth(2)
+    }
+}
+
+impl nodes::CaseArm {
+    pub fn body(&self) -> Option<Expr> {
+        support::child(self.syntax())
+    }
+}
+
+impl nodes::LabelExpr {
+    pub fn body(&self) -> Option<Expr> {
+        support::child(self.syntax())
+    }
+}
+
+impl nodes::BreakExpr {
+    /// The `label` this `break` exits, if it is in scope
+    pub fn target(&self) -> Option<nodes::LabelExpr> {
+        enclosing_label(self.syntax(), &self.name()?)
+    }
+}
+
+impl nodes::ContinueExpr {
+    /// The `label` this `continue` jumps to, if it is in scope and labels a
+    /// loop
+    pub fn target(&self) -> Option<nodes::LabelExpr> {
+        let label = enclosing_label(self.syntax(), &self.name()?)?;
+        match label.body()? {
+            nodes::Expr::WhileExpr(_) | nodes::Expr::LoopExpr(_) | nodes::Expr::ForExpr(_) => {
+                Some(label)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The innermost `label` around `node` with the given name. Labels are not
+/// visible inside nested functions, classes, objects and `async` bodies.
+fn enclosing_label(node: &SyntaxNode, name: &nodes::Name) -> Option<nodes::LabelExpr> {
+    let name = ident_text(name)?;
+    for ancestor in node.ancestors().skip(1) {
+        if let Some(label) = nodes::LabelExpr::cast(ancestor.clone()) {
+            if label.name().and_then(|n| ident_text(&n)).as_ref() == Some(&name) {
+                return Some(label);
             }
         }
+        if matches!(
+            ancestor.kind(),
+            SyntaxKind::FUNC_DECL
+                | SyntaxKind::CLASS_DECL
+                | SyntaxKind::OBJECT_DECL
+                | SyntaxKind::ASYNC_EXPR
+        ) {
+            return None;
+        }
+    }
+    None
+}
+
+impl nodes::CatchClause {
+    pub fn body(&self) -> Option<Expr> {
+        support::child(self.syntax())
+    }
+}
+
+impl nodes::FuncSort {
+    /// Query functions are shared as well
+    pub fn is_shared(&self) -> bool {
+        self.shared_token().is_some() || self.query_token().is_some()
+    }
+
+    pub fn is_query(&self) -> bool {
+        self.query_token().is_some()
+    }
+}
+
+impl nodes::FuncDecl {
+    pub fn body(&self) -> Option<Expr> {
+        support::child(self.syntax())
+    }
+}
+
+impl nodes::WhileExpr {
+    pub fn condition(&self) -> Option<Expr> {
+        support::children(self.syntax()).next()
+    }
+
+    pub fn body(&self) -> Option<Expr> {
+        support::children(self.syntax()).nth(1)
+    }
+}
+
+impl nodes::LoopExpr {
+    pub fn body(&self) -> Option<Expr> {
+        support::children(self.syntax()).next()
+    }
+
+    pub fn condition(&self) -> Option<Expr> {
+        support::children(self.syntax()).nth(1)
+    }
+}
+
+impl nodes::ForExpr {
+    pub fn body(&self) -> Option<Expr> {
+        support::children(self.syntax()).nth(1)
     }
 }
@@ -1,3 +1,4 @@
+use crate::syntax::{nodes, tokens};
 use crate::syntax::{SmolStr, SyntaxKind, SyntaxNode, SyntaxNodeChildren, SyntaxToken};
 use std::marker::PhantomData;
 
@@ -34,6 +35,45 @@ pub trait AstToken {
     }
 }
 
+/// Nodes that declare or refer to something by name, like functions, types
+/// and variables. The impls are generated from the grammar.
+pub trait HasName: AstNode {
+    fn name(&self) -> Option<nodes::Name> {
+        support::child(self.syntax())
+    }
+}
+
+pub trait HasTypeParams: AstNode {
+    fn type_params(&self) -> Option<nodes::TypeParams> {
+        support::child(self.syntax())
+    }
+}
+
+pub trait HasTypeAnnotation: AstNode {
+    fn type_annotation(&self) -> Option<nodes::TypeAnnotation> {
+        support::child(self.syntax())
+    }
+}
+
+pub trait HasVisibility: AstNode {
+    fn visibility(&self) -> Option<nodes::Visibility> {
+        support::child(self.syntax())
+    }
+}
+
+pub trait HasDocComments: AstNode {
+    /// The `///` and `/** */` comments in front of this node. Trivia is
+    /// attached to the token after it, so these are the leading trivia of
+    /// the node's first token.
+    fn doc_comments(&self) -> Vec<tokens::Comment> {
+        std::iter::successors(self.syntax().first_token(), |t| t.next_token())
+            .take_while(|t| t.kind().is_trivia())
+            .filter_map(tokens::Comment::cast)
+            .filter(|c| c.is_doc())
+            .collect()
+    }
+}
+
 /// An iterator over `SyntaxNode` children of a particular AST type.
 #[derive(Debug, Clone)]
 pub struct AstChildren<N> {
@@ -58,7 +98,7 @@ impl<N: AstNode> Iterator for AstChildren<N> {
 }
 
 pub(crate) mod support {
-    use super::{AstChildren, AstNode, SyntaxKind, SyntaxNode, SyntaxToken};
+    use super::{AstChildren, AstNode, AstToken, SyntaxKind, SyntaxNode, SyntaxToken};
 
     pub(crate) fn child<N: AstNode>(parent: &SyntaxNode) -> Option<N> {
         parent.children().find_map(N::cast)
@@ -74,4 +114,11 @@ pub(crate) mod support {
             .filter_map(|it| it.into_token())
             .find(|it| it.kind() == kind)
     }
+
+    pub(crate) fn ast_token<T: AstToken>(parent: &SyntaxNode) -> Option<T> {
+        parent
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .find_map(T::cast)
+    }
 }
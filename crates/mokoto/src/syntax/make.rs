@@ -0,0 +1,402 @@
+//! Constructors for syntax trees, so Motoko code can be generated without
+//! gluing strings together.
+//!
+//! The trees have exactly the shape the parser produces for their text,
+//! including where whitespace is attached, so printing and parsing them again
+//! gives back the same tree. Types are wrapped in parentheses where they would
+//! otherwise parse differently, like a function type inside an option.
+use crate::lexer::SyntaxKind::{self, *};
+use crate::syntax::ast::AstNode;
+use crate::syntax::nodes::{self, Expr, Pattern, Type};
+use crate::syntax::{MotokoLanguage, SyntaxNode};
+use logos::Logos;
+use rowan::{GreenNodeBuilder, Language, NodeOrToken, WalkEvent};
+
+/// A name like `x` or `Array`.
+///
+/// Panics if `text` isn't an identifier, keywords like `type` aren't.
+pub fn name(text: &str) -> nodes::Name {
+    let mut b = Builder::new(NAME);
+    b.ident(text);
+    b.build()
+}
+
+pub fn var_pat(name: nodes::Name) -> nodes::VarPat {
+    let mut b = Builder::new(VAR_PAT);
+    b.node(&name, false);
+    b.build()
+}
+
+pub fn var_expr(name: nodes::Name) -> nodes::VarExpr {
+    let mut b = Builder::new(VAR_EXPR);
+    b.node(&name, false);
+    b.build()
+}
+
+/// `let pat = expr`
+pub fn let_decl(pat: Pattern, expr: Expr) -> nodes::LetDecl {
+    let mut b = Builder::new(LET_DECL);
+    b.token_space(LET_KW, "let");
+    b.node(&pat, true);
+    b.token_space(EQUALS, "=");
+    b.node(&expr, false);
+    b.build()
+}
+
+/// `type name = ty`
+pub fn type_decl(name: nodes::Name, ty: Type) -> nodes::TypeDecl {
+    let mut b = Builder::new(TYPE_DECL);
+    b.token_space(TYPE_KW, "type");
+    b.node(&name, true);
+    b.token_space(EQUALS, "=");
+    b.typ(&ty, Prec::Bin, false);
+    b.build()
+}
+
+/// A path like `Debug.print`.
+///
+/// Panics if any of the segments isn't an identifier.
+pub fn path<'a>(segments: impl IntoIterator<Item = &'a str>) -> nodes::Path {
+    let mut b = Builder::new(PATH);
+    for (i, segment) in segments.into_iter().enumerate() {
+        if i > 0 {
+            b.token(DOT, ".");
+        }
+        b.ident(segment);
+    }
+    b.build()
+}
+
+/// A named type like `Nat` or `Array.Array<Text>`, the type arguments are
+/// left out if there are none. `Any`, `None` and `Null` have their own
+/// constructors.
+pub fn path_type<'a>(
+    segments: impl IntoIterator<Item = &'a str>,
+    args: impl IntoIterator<Item = Type>,
+) -> nodes::PathType {
+    let mut b = Builder::new(PATH_TYPE);
+    b.node(&path(segments), false);
+    let args: Vec<_> = args.into_iter().collect();
+    if !args.is_empty() {
+        b.start(TYPE_ARGS);
+        b.token(L_ANGLE, "<");
+        b.types(&args, Prec::Bin);
+        b.token(R_ANGLE, ">");
+        b.finish();
+    }
+    b.build()
+}
+
+/// `Any`
+pub fn any_type() -> nodes::AnyType {
+    let mut b = Builder::new(ANY_TYPE);
+    b.ident("Any");
+    b.build()
+}
+
+/// `None`
+pub fn none_type() -> nodes::NoneType {
+    let mut b = Builder::new(NONE_TYPE);
+    b.ident("None");
+    b.build()
+}
+
+/// `Null`
+pub fn null_type() -> nodes::NullType {
+    let mut b = Builder::new(NULL_TYPE);
+    b.ident("Null");
+    b.build()
+}
+
+/// `(ty)`
+pub fn paren_type(ty: Type) -> nodes::ParenType {
+    let mut b = Builder::new(PAREN_TYPE);
+    b.token(L_PAREN, "(");
+    b.typ(&ty, Prec::Item, false);
+    b.token(R_PAREN, ")");
+    b.build()
+}
+
+/// A tuple type like `()` or `(Nat, Text)`.
+///
+/// Panics when given a single type, as `(Nat)` is a [paren_type].
+pub fn tuple_type(fields: impl IntoIterator<Item = Type>) -> nodes::TupleType {
+    let fields: Vec<_> = fields.into_iter().collect();
+    assert!(fields.len() != 1, "A single type in parens is a paren type");
+    let mut b = Builder::new(TUPLE_TYPE);
+    b.token(L_PAREN, "(");
+    b.types(&fields, Prec::Item);
+    b.token(R_PAREN, ")");
+    b.build()
+}
+
+/// `?ty`
+pub fn optional_type(ty: Type) -> nodes::OptionalType {
+    let mut b = Builder::new(OPTIONAL_TYPE);
+    b.token(QUESTION, "?");
+    b.typ(&ty, Prec::Un, false);
+    b.build()
+}
+
+/// `[ty]`, or `[var ty]` for mutable arrays
+pub fn array_type(mutable: bool, ty: Type) -> nodes::ArrayType {
+    let mut b = Builder::new(ARRAY_TYPE);
+    b.token(L_BRACKET, "[");
+    if mutable {
+        b.token_space(VAR_KW, "var");
+    }
+    b.typ(&ty, Prec::Bin, false);
+    b.token(R_BRACKET, "]");
+    b.build()
+}
+
+/// `async ty`
+pub fn async_type(ty: Type) -> nodes::AsyncType {
+    let mut b = Builder::new(ASYNC_TYPE);
+    b.token_space(ASYNC_KW, "async");
+    b.typ(&ty, Prec::Pre, false);
+    b.build()
+}
+
+/// A function type like `(Nat, Text) -> Bool`
+pub fn func_type(args: impl IntoIterator<Item = Type>, result: Type) -> nodes::FuncType {
+    let mut b = Builder::new(FUNC_TYPE);
+    b.func_signature(args, &result);
+    b.build()
+}
+
+/// The type of a public actor method, like `shared Nat -> async ()` or
+/// `shared query () -> async Text`
+pub fn shared_func_type(
+    query: bool,
+    args: impl IntoIterator<Item = Type>,
+    result: Type,
+) -> nodes::FuncType {
+    let mut b = Builder::new(FUNC_TYPE);
+    b.start(FUNC_SORT);
+    b.token_space(SHARED_KW, "shared");
+    if query {
+        b.token_space(QUERY_KW, "query");
+    }
+    b.finish();
+    b.func_signature(args, &result);
+    b.build()
+}
+
+/// A field of an object type like `name : Text`, or `var count : Nat` for
+/// mutable fields
+pub fn type_field(mutable: bool, name: nodes::Name, ty: Type) -> nodes::TypeField {
+    let mut b = Builder::new(TYPE_FIELD);
+    if mutable {
+        b.token_space(VAR_KW, "var");
+    }
+    b.node(&name, true);
+    b.annotation(&ty, false);
+    b.build()
+}
+
+/// A record type like `{ name : Text; age : Nat }`
+pub fn object_type(fields: impl IntoIterator<Item = nodes::TypeField>) -> nodes::ObjectType {
+    let mut b = Builder::new(OBJECT_TYPE);
+    b.fields(fields);
+    b.build()
+}
+
+/// The type of an actor, like `actor { get : shared () -> async Nat }`
+pub fn actor_type(fields: impl IntoIterator<Item = nodes::TypeField>) -> nodes::ObjectType {
+    let mut b = Builder::new(OBJECT_TYPE);
+    b.start(OBJECT_SORT);
+    b.token_space(ACTOR_KW, "actor");
+    b.finish();
+    b.fields(fields);
+    b.build()
+}
+
+/// A tag of a variant type like `#ok : Nat`, or just `#none`
+pub fn type_tag(name: nodes::Name, ty: Option<Type>) -> nodes::TypeTag {
+    let mut b = Builder::new(TYPE_TAG);
+    b.token(HASH, "#");
+    b.node(&name, ty.is_some());
+    if let Some(ty) = ty {
+        b.annotation(&ty, false);
+    }
+    b.build()
+}
+
+/// A variant type like `{ #ok : Nat; #err : Text }`, or `{#}` if there are
+/// no tags
+pub fn variant_type(tags: impl IntoIterator<Item = nodes::TypeTag>) -> nodes::VariantType {
+    let tags: Vec<_> = tags.into_iter().collect();
+    let mut b = Builder::new(VARIANT_TYPE);
+    if tags.is_empty() {
+        b.token(L_BRACE, "{");
+        b.token(HASH, "#");
+        b.token(R_BRACE, "}");
+    } else {
+        b.separated(&tags);
+    }
+    b.build()
+}
+
+/// How tightly a type binds, following the `typ_*` functions of the parser.
+/// Types are wrapped in parentheses when they appear where something binding
+/// tighter is expected.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Prec {
+    /// Named types like `x : Nat` are only allowed in tuples
+    Item,
+    Bin,
+    NoBin,
+    Pre,
+    Un,
+    Nullary,
+}
+
+fn prec(ty: &Type) -> Prec {
+    match ty {
+        Type::NamedType(_) => Prec::Item,
+        Type::AndType(_) | Type::OrType(_) => Prec::Bin,
+        Type::FuncType(_) | Type::ActorClassType(_) => Prec::NoBin,
+        Type::AsyncType(_) | Type::PrimType(_) => Prec::Pre,
+        Type::ObjectType(ty) if ty.object_sort().is_some() => Prec::Pre,
+        Type::OptionalType(_) => Prec::Un,
+        Type::ParenType(_)
+        | Type::PathType(_)
+        | Type::TupleType(_)
+        | Type::ArrayType(_)
+        | Type::ObjectType(_)
+        | Type::VariantType(_)
+        | Type::AnyType(_)
+        | Type::NoneType(_)
+        | Type::NullType(_) => Prec::Nullary,
+    }
+}
+
+struct Builder {
+    inner: GreenNodeBuilder<'static>,
+}
+
+impl Builder {
+    fn new(kind: SyntaxKind) -> Builder {
+        let mut builder = Builder {
+            inner: GreenNodeBuilder::new(),
+        };
+        builder.start(kind);
+        builder
+    }
+
+    fn build<N: AstNode>(mut self) -> N {
+        self.finish();
+        let node = SyntaxNode::new_root(self.inner.finish());
+        N::cast(node).unwrap()
+    }
+
+    fn start(&mut self, kind: SyntaxKind) {
+        self.inner.start_node(MotokoLanguage::kind_to_raw(kind))
+    }
+
+    fn finish(&mut self) {
+        self.inner.finish_node()
+    }
+
+    fn token(&mut self, kind: SyntaxKind, text: &str) {
+        self.inner
+            .token(MotokoLanguage::kind_to_raw(kind), text.into())
+    }
+
+    /// Spaces after a token belong to the token, so they end up in the same
+    /// node
+    fn token_space(&mut self, kind: SyntaxKind, text: &str) {
+        self.token(kind, text);
+        self.token(SPACE, " ");
+    }
+
+    fn ident(&mut self, text: &str) {
+        let mut lexer = SyntaxKind::lexer(text);
+        let is_ident = lexer.next() == Some(IDENT) && lexer.next().is_none();
+        assert!(is_ident, "{:?} is not an identifier", text);
+        self.token(IDENT, text)
+    }
+
+    /// Copies `node` into the tree. A space after it belongs to its last
+    /// token, which can be nested arbitrarily deep.
+    fn node(&mut self, node: &impl AstNode, space: bool) {
+        let last = node.syntax().last_token();
+        for event in node.syntax().preorder_with_tokens() {
+            match event {
+                WalkEvent::Enter(NodeOrToken::Node(node)) => self.start(node.kind()),
+                WalkEvent::Leave(NodeOrToken::Node(_)) => self.finish(),
+                WalkEvent::Enter(NodeOrToken::Token(token)) => {
+                    self.token(token.kind(), token.text());
+                    if space && Some(&token) == last.as_ref() {
+                        self.token(SPACE, " ");
+                    }
+                }
+                WalkEvent::Leave(NodeOrToken::Token(_)) => {}
+            }
+        }
+    }
+
+    /// A type where at least `min` is expected, in parentheses if necessary
+    fn typ(&mut self, ty: &Type, min: Prec, space: bool) {
+        if prec(ty) >= min {
+            self.node(ty, space)
+        } else {
+            self.node(&paren_type(ty.clone()), space)
+        }
+    }
+
+    fn types(&mut self, types: &[Type], min: Prec) {
+        for (i, ty) in types.iter().enumerate() {
+            if i > 0 {
+                self.token_space(COMMA, ",");
+            }
+            self.typ(ty, min, false);
+        }
+    }
+
+    fn annotation(&mut self, ty: &Type, space: bool) {
+        self.start(TYPE_ANNOTATION);
+        self.token_space(COLON, ":");
+        self.typ(ty, Prec::Bin, space);
+        self.finish();
+    }
+
+    fn func_signature(&mut self, args: impl IntoIterator<Item = Type>, result: &Type) {
+        let mut args: Vec<_> = args.into_iter().collect();
+        let arg: Type = if args.len() == 1 {
+            paren_type(args.remove(0)).into()
+        } else {
+            tuple_type(args).into()
+        };
+        self.start(FUNC_ARG);
+        self.node(&arg, true);
+        self.finish();
+        self.token_space(ARROW, "->");
+        self.start(FUNC_RESULT);
+        self.typ(result, Prec::NoBin, false);
+        self.finish();
+    }
+
+    fn fields(&mut self, fields: impl IntoIterator<Item = nodes::TypeField>) {
+        let fields: Vec<_> = fields.into_iter().collect();
+        if fields.is_empty() {
+            self.token(L_BRACE, "{");
+            self.token(R_BRACE, "}");
+        } else {
+            self.separated(&fields);
+        }
+    }
+
+    /// `{ a; b }`
+    fn separated<N: AstNode>(&mut self, items: &[N]) {
+        self.token_space(L_BRACE, "{");
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.token_space(SEMICOLON, ";");
+            }
+            self.node(item, i == items.len() - 1);
+        }
+        self.token(R_BRACE, "}");
+    }
+}
@@ -1,16 +1,41 @@
+//! Generated by `cargo xtask codegen`, do not edit by hand.
+
 use super::{
     ast::{self, support, AstChildren, AstNode},
+    tokens::{CharLit, FloatLit, Ident, NatLit, TextLit},
     SyntaxKind::{self, *},
     SyntaxNode, SyntaxToken,
 };
 use crate::T;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Name {
+pub struct SourceFile {
     pub(crate) syntax: SyntaxNode,
 }
-impl Name {
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![ident])
+impl SourceFile {
+    pub fn imports(&self) -> AstChildren<ImportDecl> {
+        support::children(&self.syntax)
+    }
+    pub fn decls(&self) -> AstChildren<Decl> {
+        support::children(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ImportDecl {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasDocComments for ImportDecl {}
+impl ImportDecl {
+    pub fn import_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![import])
+    }
+    pub fn pattern(&self) -> Option<Pattern> {
+        support::child(&self.syntax)
+    }
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![=])
+    }
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -18,8 +43,29 @@ pub struct Literal {
     pub(crate) syntax: SyntaxNode,
 }
 impl Literal {
-    pub fn number_lit_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![number_lit])
+    pub fn nat_lit_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![nat_lit])
+    }
+    pub fn nat_lit(&self) -> Option<NatLit> {
+        support::ast_token(&self.syntax)
+    }
+    pub fn float_lit_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![float_lit])
+    }
+    pub fn float_lit(&self) -> Option<FloatLit> {
+        support::ast_token(&self.syntax)
+    }
+    pub fn text_lit_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![text_lit])
+    }
+    pub fn text_lit(&self) -> Option<TextLit> {
+        support::ast_token(&self.syntax)
+    }
+    pub fn char_lit_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![char_lit])
+    }
+    pub fn char_lit(&self) -> Option<CharLit> {
+        support::ast_token(&self.syntax)
     }
     pub fn true_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![true])
@@ -32,6 +78,18 @@ impl Literal {
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Name {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Name {
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![ident])
+    }
+    pub fn ident(&self) -> Option<Ident> {
+        support::ast_token(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct OptionalType {
     pub(crate) syntax: SyntaxNode,
 }
@@ -66,6 +124,9 @@ impl AsyncType {
     pub fn async_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![async])
     }
+    pub fn async_star_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![async *])
+    }
     pub fn ty(&self) -> Option<Type> {
         support::child(&self.syntax)
     }
@@ -105,6 +166,9 @@ impl ArrayType {
     pub fn l_brack_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T!['['])
     }
+    pub fn var_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![var])
+    }
     pub fn ty(&self) -> Option<Type> {
         support::child(&self.syntax)
     }
@@ -116,14 +180,9 @@ impl ArrayType {
 pub struct FuncType {
     pub(crate) syntax: SyntaxNode,
 }
+impl ast::HasTypeParams for FuncType {}
 impl FuncType {
-    pub fn shared_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![shared])
-    }
-    pub fn query_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![query])
-    }
-    pub fn type_params(&self) -> Option<TypeParams> {
+    pub fn func_sort(&self) -> Option<FuncSort> {
         support::child(&self.syntax)
     }
     pub fn func_arg(&self) -> Option<FuncArg> {
@@ -147,7 +206,7 @@ impl ObjectType {
     pub fn l_brace_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T!['{'])
     }
-    pub fn fields(&self) -> AstChildren<TypeField> {
+    pub fn fields(&self) -> AstChildren<ObjectField> {
         support::children(&self.syntax)
     }
     pub fn r_brace_token(&self) -> Option<SyntaxToken> {
@@ -162,6 +221,9 @@ impl VariantType {
     pub fn l_brace_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T!['{'])
     }
+    pub fn hash_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![#])
+    }
     pub fn variants(&self) -> AstChildren<TypeTag> {
         support::children(&self.syntax)
     }
@@ -173,10 +235,8 @@ impl VariantType {
 pub struct NamedType {
     pub(crate) syntax: SyntaxNode,
 }
+impl ast::HasName for NamedType {}
 impl NamedType {
-    pub fn name(&self) -> Option<Name> {
-        support::child(&self.syntax)
-    }
     pub fn colon_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![:])
     }
@@ -192,7 +252,83 @@ impl PrimType {
     pub fn prim_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![prim])
     }
-    pub fn name(&self) -> Option<Name> {
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AndType {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AndType {
+    pub fn and_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![and])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OrType {
+    pub(crate) syntax: SyntaxNode,
+}
+impl OrType {
+    pub fn or_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![or])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnyType {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AnyType {
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![ident])
+    }
+    pub fn ident(&self) -> Option<Ident> {
+        support::ast_token(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NoneType {
+    pub(crate) syntax: SyntaxNode,
+}
+impl NoneType {
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![ident])
+    }
+    pub fn ident(&self) -> Option<Ident> {
+        support::ast_token(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NullType {
+    pub(crate) syntax: SyntaxNode,
+}
+impl NullType {
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![ident])
+    }
+    pub fn ident(&self) -> Option<Ident> {
+        support::ast_token(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ActorClassType {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasTypeParams for ActorClassType {}
+impl ActorClassType {
+    pub fn actor_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![actor])
+    }
+    pub fn class_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![class])
+    }
+    pub fn func_arg(&self) -> Option<FuncArg> {
+        support::child(&self.syntax)
+    }
+    pub fn arrow_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![->])
+    }
+    pub fn func_result(&self) -> Option<FuncResult> {
         support::child(&self.syntax)
     }
 }
@@ -201,8 +337,14 @@ pub struct Path {
     pub(crate) syntax: SyntaxNode,
 }
 impl Path {
-    pub fn name(&self) -> Option<Name> {
-        support::child(&self.syntax)
+    pub fn ident_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![ident])
+    }
+    pub fn ident(&self) -> Option<Ident> {
+        support::ast_token(&self.syntax)
+    }
+    pub fn dot_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![.])
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -221,6 +363,21 @@ impl TypeArgs {
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FuncSort {
+    pub(crate) syntax: SyntaxNode,
+}
+impl FuncSort {
+    pub fn shared_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![shared])
+    }
+    pub fn query_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![query])
+    }
+    pub fn caller(&self) -> Option<Pattern> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TypeParams {
     pub(crate) syntax: SyntaxNode,
 }
@@ -257,10 +414,8 @@ impl FuncResult {
 pub struct TypeBind {
     pub(crate) syntax: SyntaxNode,
 }
+impl ast::HasName for TypeBind {}
 impl TypeBind {
-    pub fn path(&self) -> Option<Path> {
-        support::child(&self.syntax)
-    }
     pub fn type_bound(&self) -> Option<TypeBound> {
         support::child(&self.syntax)
     }
@@ -285,37 +440,38 @@ impl ObjectSort {
     pub fn object_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![object])
     }
-    pub fn class_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![class])
-    }
     pub fn actor_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![actor])
     }
+    pub fn module_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![module])
+    }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TypeField {
     pub(crate) syntax: SyntaxNode,
 }
+impl ast::HasName for TypeField {}
+impl ast::HasTypeAnnotation for TypeField {}
 impl TypeField {
     pub fn var_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![var])
     }
-    pub fn type_annotation(&self) -> Option<TypeAnnotation> {
-        support::child(&self.syntax)
-    }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TypeFieldFunc {
     pub(crate) syntax: SyntaxNode,
 }
+impl ast::HasName for TypeFieldFunc {}
+impl ast::HasTypeParams for TypeFieldFunc {}
 impl TypeFieldFunc {
-    pub fn name(&self) -> Option<Name> {
+    pub fn func_arg(&self) -> Option<FuncArg> {
         support::child(&self.syntax)
     }
-    pub fn type_params(&self) -> Option<TypeParams> {
-        support::child(&self.syntax)
+    pub fn colon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![:])
     }
-    pub fn func_type(&self) -> Option<FuncType> {
+    pub fn func_result(&self) -> Option<FuncResult> {
         support::child(&self.syntax)
     }
 }
@@ -335,16 +491,12 @@ impl TypeAnnotation {
 pub struct TypeTag {
     pub(crate) syntax: SyntaxNode,
 }
+impl ast::HasName for TypeTag {}
+impl ast::HasTypeAnnotation for TypeTag {}
 impl TypeTag {
     pub fn hash_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![#])
     }
-    pub fn name(&self) -> Option<Name> {
-        support::child(&self.syntax)
-    }
-    pub fn type_annotation(&self) -> Option<TypeAnnotation> {
-        support::child(&self.syntax)
-    }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WildcardPat {
@@ -359,11 +511,8 @@ impl WildcardPat {
 pub struct VarPat {
     pub(crate) syntax: SyntaxNode,
 }
-impl VarPat {
-    pub fn name(&self) -> Option<Name> {
-        support::child(&self.syntax)
-    }
-}
+impl ast::HasName for VarPat {}
+impl VarPat {}
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LiteralPat {
     pub(crate) syntax: SyntaxNode,
@@ -408,14 +557,14 @@ pub struct ObjectPat {
     pub(crate) syntax: SyntaxNode,
 }
 impl ObjectPat {
-    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T!['('])
+    pub fn l_brace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['{'])
     }
     pub fn fields(&self) -> AstChildren<PatternField> {
         support::children(&self.syntax)
     }
-    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![')'])
+    pub fn r_brace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['}'])
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -431,28 +580,50 @@ impl VariantPat {
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PatternFieldPun {
+pub struct OptionPat {
     pub(crate) syntax: SyntaxNode,
 }
-impl PatternFieldPun {
-    pub fn name(&self) -> Option<Name> {
-        support::child(&self.syntax)
+impl OptionPat {
+    pub fn question_mark_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![?])
     }
-    pub fn type_annotation(&self) -> Option<TypeAnnotation> {
+    pub fn pattern(&self) -> Option<Pattern> {
         support::child(&self.syntax)
     }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PatternFieldPat {
+pub struct AltPat {
     pub(crate) syntax: SyntaxNode,
 }
-impl PatternFieldPat {
-    pub fn ident_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, T![ident])
+impl AltPat {
+    pub fn or_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![or])
     }
-    pub fn type_annotation(&self) -> Option<TypeAnnotation> {
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnnotPat {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasTypeAnnotation for AnnotPat {}
+impl AnnotPat {
+    pub fn pattern(&self) -> Option<Pattern> {
         support::child(&self.syntax)
     }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PatternFieldPun {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for PatternFieldPun {}
+impl ast::HasTypeAnnotation for PatternFieldPun {}
+impl PatternFieldPun {}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PatternFieldPat {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for PatternFieldPat {}
+impl ast::HasTypeAnnotation for PatternFieldPat {}
+impl PatternFieldPat {
     pub fn eq_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![=])
     }
@@ -471,77 +642,2189 @@ impl Tag {
     pub fn ident_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, T![ident])
     }
+    pub fn ident(&self) -> Option<Ident> {
+        support::ast_token(&self.syntax)
+    }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum Type {
-    OptionalType(OptionalType),
-    ParenType(ParenType),
-    AsyncType(AsyncType),
-    PathType(PathType),
-    TupleType(TupleType),
-    ArrayType(ArrayType),
-    FuncType(FuncType),
-    ObjectType(ObjectType),
-    VariantType(VariantType),
-    NamedType(NamedType),
-    PrimType(PrimType),
+pub struct LiteralExpr {
+    pub(crate) syntax: SyntaxNode,
 }
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ObjectField {
-    TypeField(TypeField),
-    TypeFieldFunc(TypeFieldFunc),
+impl LiteralExpr {
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
+    }
 }
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum Pattern {
-    WildcardPat(WildcardPat),
-    VarPat(VarPat),
-    LiteralPat(LiteralPat),
-    ParenPat(ParenPat),
-    TuplePat(TuplePat),
-    ObjectPat(ObjectPat),
-    VariantPat(VariantPat),
+pub struct VarExpr {
+    pub(crate) syntax: SyntaxNode,
 }
+impl ast::HasName for VarExpr {}
+impl VarExpr {}
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum PatternField {
-    PatternFieldPun(PatternFieldPun),
-    PatternFieldPat(PatternFieldPat),
+pub struct ParenExpr {
+    pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for Name {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == NAME
+impl ParenExpr {
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['('])
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![')'])
     }
 }
-impl AstNode for Literal {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == LITERAL
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TupleExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TupleExpr {
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['('])
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+    pub fn exprs(&self) -> AstChildren<Expr> {
+        support::children(&self.syntax)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![')'])
     }
 }
-impl AstNode for OptionalType {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == OPTIONAL_TYPE
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrimExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl PrimExpr {
+    pub fn prim_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![prim])
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
+    pub fn literal(&self) -> Option<Literal> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArrayExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ArrayExpr {
+    pub fn l_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['['])
+    }
+    pub fn var_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![var])
+    }
+    pub fn elements(&self) -> AstChildren<Expr> {
+        support::children(&self.syntax)
+    }
+    pub fn r_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![']'])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IndexExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl IndexExpr {
+    pub fn l_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['['])
+    }
+    pub fn r_brack_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![']'])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ProjExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    pub fn dot_num_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![dot_num])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DotExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for DotExpr {}
+impl DotExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    pub fn dot_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![.])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CallExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CallExpr {
+    pub fn callee(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    pub fn type_args(&self) -> Option<TypeArgs> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VariantExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl VariantExpr {
+    pub fn tag(&self) -> Option<Tag> {
+        support::child(&self.syntax)
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OptionExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl OptionExpr {
+    pub fn question_mark_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![?])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnaryExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl UnaryExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ActorUrlExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ActorUrlExpr {
+    pub fn actor_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![actor])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ShowExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ShowExpr {
+    pub fn debug_show_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![debug_show])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BinExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl BinExpr {}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnnotExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasTypeAnnotation for AnnotExpr {}
+impl AnnotExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AssignExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AssignExpr {}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReturnExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ReturnExpr {
+    pub fn return_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![return])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AsyncExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AsyncExpr {
+    pub fn async_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![async])
+    }
+    pub fn async_star_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![async *])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AwaitExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AwaitExpr {
+    pub fn await_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![await])
+    }
+    pub fn await_star_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![await *])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AssertExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AssertExpr {
+    pub fn assert_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![assert])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DebugExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl DebugExpr {
+    pub fn debug_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![debug])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IfExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl IfExpr {
+    pub fn if_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![if])
+    }
+    pub fn else_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![else])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SwitchExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SwitchExpr {
+    pub fn switch_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![switch])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    pub fn l_brace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['{'])
+    }
+    pub fn arms(&self) -> AstChildren<CaseArm> {
+        support::children(&self.syntax)
+    }
+    pub fn r_brace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['}'])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TryExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TryExpr {
+    pub fn try_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![try])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    pub fn catch_clause(&self) -> Option<CatchClause> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ThrowExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ThrowExpr {
+    pub fn throw_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![throw])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WhileExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl WhileExpr {
+    pub fn while_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![while])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LoopExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl LoopExpr {
+    pub fn loop_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![loop])
+    }
+    pub fn while_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![while])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ForExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ForExpr {
+    pub fn for_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![for])
+    }
+    pub fn l_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['('])
+    }
+    pub fn pattern(&self) -> Option<Pattern> {
+        support::child(&self.syntax)
+    }
+    pub fn in_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![in])
+    }
+    pub fn iterable(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+    pub fn r_paren_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![')'])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LabelExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for LabelExpr {}
+impl ast::HasTypeAnnotation for LabelExpr {}
+impl LabelExpr {
+    pub fn label_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![label])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BreakExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for BreakExpr {}
+impl BreakExpr {
+    pub fn break_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![break])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContinueExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for ContinueExpr {}
+impl ContinueExpr {
+    pub fn continue_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![continue])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IgnoreExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl IgnoreExpr {
+    pub fn ignore_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![ignore])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlockExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl BlockExpr {
+    pub fn l_brace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['{'])
+    }
+    pub fn decls(&self) -> AstChildren<Decl> {
+        support::children(&self.syntax)
+    }
+    pub fn r_brace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['}'])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DoExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl DoExpr {
+    pub fn do_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![do])
+    }
+    pub fn block_expr(&self) -> Option<BlockExpr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ObjectExpr {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ObjectExpr {
+    pub fn l_brace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['{'])
+    }
+    pub fn bases(&self) -> AstChildren<Expr> {
+        support::children(&self.syntax)
+    }
+    pub fn with_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![with])
+    }
+    pub fn fields(&self) -> AstChildren<ExpField> {
+        support::children(&self.syntax)
+    }
+    pub fn r_brace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['}'])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LetDecl {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasDocComments for LetDecl {}
+impl LetDecl {
+    pub fn let_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![let])
+    }
+    pub fn pattern(&self) -> Option<Pattern> {
+        support::child(&self.syntax)
+    }
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![=])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VarDecl {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for VarDecl {}
+impl ast::HasTypeAnnotation for VarDecl {}
+impl ast::HasDocComments for VarDecl {}
+impl VarDecl {
+    pub fn var_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![var])
+    }
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![=])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TypeDecl {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for TypeDecl {}
+impl ast::HasTypeParams for TypeDecl {}
+impl ast::HasDocComments for TypeDecl {}
+impl TypeDecl {
+    pub fn type_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![type])
+    }
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![=])
+    }
+    pub fn ty(&self) -> Option<Type> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FuncDecl {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for FuncDecl {}
+impl ast::HasTypeParams for FuncDecl {}
+impl ast::HasTypeAnnotation for FuncDecl {}
+impl ast::HasDocComments for FuncDecl {}
+impl FuncDecl {
+    pub fn func_sort(&self) -> Option<FuncSort> {
+        support::child(&self.syntax)
+    }
+    pub fn func_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![func])
+    }
+    pub fn param(&self) -> Option<Pattern> {
+        support::child(&self.syntax)
+    }
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![=])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClassDecl {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for ClassDecl {}
+impl ast::HasTypeParams for ClassDecl {}
+impl ast::HasTypeAnnotation for ClassDecl {}
+impl ast::HasDocComments for ClassDecl {}
+impl ClassDecl {
+    pub fn func_sort(&self) -> Option<FuncSort> {
+        support::child(&self.syntax)
+    }
+    pub fn object_sort(&self) -> Option<ObjectSort> {
+        support::child(&self.syntax)
+    }
+    pub fn class_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![class])
+    }
+    pub fn param(&self) -> Option<Pattern> {
+        support::child(&self.syntax)
+    }
+    pub fn class_body(&self) -> Option<ClassBody> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ObjectDecl {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for ObjectDecl {}
+impl ast::HasDocComments for ObjectDecl {}
+impl ObjectDecl {
+    pub fn object_sort(&self) -> Option<ObjectSort> {
+        support::child(&self.syntax)
+    }
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![=])
+    }
+    pub fn object_body(&self) -> Option<ObjectBody> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CaseArm {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CaseArm {
+    pub fn case_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![case])
+    }
+    pub fn pattern(&self) -> Option<Pattern> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CatchClause {
+    pub(crate) syntax: SyntaxNode,
+}
+impl CatchClause {
+    pub fn catch_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![catch])
+    }
+    pub fn pattern(&self) -> Option<Pattern> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExpField {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for ExpField {}
+impl ast::HasTypeAnnotation for ExpField {}
+impl ExpField {
+    pub fn var_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![var])
+    }
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![=])
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExpDecl {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasDocComments for ExpDecl {}
+impl ExpDecl {
+    pub fn expr(&self) -> Option<Expr> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClassBody {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasName for ClassBody {}
+impl ClassBody {
+    pub fn eq_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![=])
+    }
+    pub fn object_body(&self) -> Option<ObjectBody> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ObjectBody {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ObjectBody {
+    pub fn l_brace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['{'])
+    }
+    pub fn fields(&self) -> AstChildren<DeclField> {
+        support::children(&self.syntax)
+    }
+    pub fn r_brace_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T!['}'])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeclField {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ast::HasVisibility for DeclField {}
+impl ast::HasDocComments for DeclField {}
+impl DeclField {
+    pub fn stability(&self) -> Option<Stability> {
+        support::child(&self.syntax)
+    }
+    pub fn decl(&self) -> Option<Decl> {
+        support::child(&self.syntax)
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Visibility {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Visibility {
+    pub fn public_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![public])
+    }
+    pub fn private_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![private])
+    }
+    pub fn system_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![system])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Stability {
+    pub(crate) syntax: SyntaxNode,
+}
+impl Stability {
+    pub fn stable_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![stable])
+    }
+    pub fn flexible_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![flexible])
+    }
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Decl {
+    LetDecl(LetDecl),
+    VarDecl(VarDecl),
+    TypeDecl(TypeDecl),
+    FuncDecl(FuncDecl),
+    ClassDecl(ClassDecl),
+    ObjectDecl(ObjectDecl),
+    ExpDecl(ExpDecl),
+}
+impl ast::HasDocComments for Decl {}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Pattern {
+    WildcardPat(WildcardPat),
+    VarPat(VarPat),
+    LiteralPat(LiteralPat),
+    ParenPat(ParenPat),
+    TuplePat(TuplePat),
+    ObjectPat(ObjectPat),
+    VariantPat(VariantPat),
+    OptionPat(OptionPat),
+    AltPat(AltPat),
+    AnnotPat(AnnotPat),
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Type {
+    OptionalType(OptionalType),
+    ParenType(ParenType),
+    AsyncType(AsyncType),
+    PathType(PathType),
+    TupleType(TupleType),
+    ArrayType(ArrayType),
+    FuncType(FuncType),
+    ObjectType(ObjectType),
+    VariantType(VariantType),
+    NamedType(NamedType),
+    PrimType(PrimType),
+    AndType(AndType),
+    OrType(OrType),
+    AnyType(AnyType),
+    NoneType(NoneType),
+    NullType(NullType),
+    ActorClassType(ActorClassType),
+}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObjectField {
+    TypeField(TypeField),
+    TypeFieldFunc(TypeFieldFunc),
+}
+impl ast::HasName for ObjectField {}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PatternField {
+    PatternFieldPun(PatternFieldPun),
+    PatternFieldPat(PatternFieldPat),
+}
+impl ast::HasName for PatternField {}
+impl ast::HasTypeAnnotation for PatternField {}
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Expr {
+    LiteralExpr(LiteralExpr),
+    VarExpr(VarExpr),
+    ParenExpr(ParenExpr),
+    TupleExpr(TupleExpr),
+    PrimExpr(PrimExpr),
+    ArrayExpr(ArrayExpr),
+    IndexExpr(IndexExpr),
+    ProjExpr(ProjExpr),
+    DotExpr(DotExpr),
+    CallExpr(CallExpr),
+    VariantExpr(VariantExpr),
+    OptionExpr(OptionExpr),
+    UnaryExpr(UnaryExpr),
+    ActorUrlExpr(ActorUrlExpr),
+    ShowExpr(ShowExpr),
+    BinExpr(BinExpr),
+    AnnotExpr(AnnotExpr),
+    AssignExpr(AssignExpr),
+    ReturnExpr(ReturnExpr),
+    AsyncExpr(AsyncExpr),
+    AwaitExpr(AwaitExpr),
+    AssertExpr(AssertExpr),
+    DebugExpr(DebugExpr),
+    IfExpr(IfExpr),
+    SwitchExpr(SwitchExpr),
+    TryExpr(TryExpr),
+    ThrowExpr(ThrowExpr),
+    WhileExpr(WhileExpr),
+    LoopExpr(LoopExpr),
+    ForExpr(ForExpr),
+    LabelExpr(LabelExpr),
+    BreakExpr(BreakExpr),
+    ContinueExpr(ContinueExpr),
+    IgnoreExpr(IgnoreExpr),
+    BlockExpr(BlockExpr),
+    DoExpr(DoExpr),
+    ObjectExpr(ObjectExpr),
+    LetDecl(LetDecl),
+    VarDecl(VarDecl),
+    TypeDecl(TypeDecl),
+    FuncDecl(FuncDecl),
+    ClassDecl(ClassDecl),
+    ObjectDecl(ObjectDecl),
+}
+impl AstNode for SourceFile {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SOURCE_FILE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ImportDecl {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == IMPORT_DECL
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for Literal {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == LITERAL
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for Name {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == NAME
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for OptionalType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == OPTIONAL_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ParenType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == PAREN_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AsyncType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == ASYNC_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for PathType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == PATH_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for TupleType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TUPLE_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ArrayType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == ARRAY_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for FuncType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == FUNC_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ObjectType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == OBJECT_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for VariantType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == VARIANT_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for NamedType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == NAMED_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for PrimType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == PRIM_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AndType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == AND_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for OrType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == OR_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AnyType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == ANY_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for NoneType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == NONE_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for NullType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == NULL_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ActorClassType {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == ACTOR_CLASS_TYPE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for Path {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == PATH
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for TypeArgs {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TYPE_ARGS
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for FuncSort {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == FUNC_SORT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for TypeParams {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TYPE_PARAMS
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for FuncArg {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == FUNC_ARG
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for FuncResult {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == FUNC_RESULT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for TypeBind {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TYPE_BIND
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for TypeBound {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TYPE_BOUND
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ObjectSort {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == OBJECT_SORT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for TypeField {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TYPE_FIELD
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for TypeFieldFunc {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TYPE_FIELD_FUNC
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for TypeAnnotation {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TYPE_ANNOTATION
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for TypeTag {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TYPE_TAG
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for WildcardPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == WILDCARD_PAT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for VarPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == VAR_PAT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for LiteralPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == LITERAL_PAT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ParenPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == PAREN_PAT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for TuplePat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TUPLE_PAT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ObjectPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == OBJECT_PAT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for VariantPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == VARIANT_PAT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for OptionPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == OPTION_PAT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AltPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == ALT_PAT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AnnotPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == ANNOT_PAT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for PatternFieldPun {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == PATTERN_FIELD_PUN
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for PatternFieldPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == PATTERN_FIELD_PAT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for Tag {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TAG
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for LiteralExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == LITERAL_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for VarExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == VAR_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ParenExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == PAREN_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for TupleExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TUPLE_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for PrimExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == PRIM_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ArrayExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == ARRAY_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for IndexExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == INDEX_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ProjExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == PROJ_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for DotExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == DOT_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for CallExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CALL_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for VariantExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == VARIANT_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for OptionExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == OPTION_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for UnaryExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == UNARY_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ActorUrlExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == ACTOR_URL_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ShowExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SHOW_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for BinExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == BIN_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AnnotExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == ANNOT_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AssignExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == ASSIGN_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ReturnExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == RETURN_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AsyncExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == ASYNC_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AwaitExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == AWAIT_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for AssertExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == ASSERT_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for DebugExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == DEBUG_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for IfExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == IF_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for SwitchExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SWITCH_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for TryExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TRY_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ThrowExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == THROW_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for WhileExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == WHILE_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for LoopExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == LOOP_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ForExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == FOR_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for LabelExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == LABEL_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for BreakExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == BREAK_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ContinueExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CONTINUE_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for IgnoreExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == IGNORE_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for BlockExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == BLOCK_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for DoExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == DO_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ObjectExpr {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == OBJECT_EXPR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for LetDecl {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == LET_DECL
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for VarDecl {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == VAR_DECL
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for TypeDecl {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TYPE_DECL
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for FuncDecl {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == FUNC_DECL
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ClassDecl {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CLASS_DECL
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for ObjectDecl {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == OBJECT_DECL
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for CaseArm {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CASE_ARM
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for CatchClause {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CATCH_CLAUSE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
         } else {
             None
@@ -551,9 +2834,9 @@ impl AstNode for OptionalType {
         &self.syntax
     }
 }
-impl AstNode for ParenType {
+impl AstNode for ExpField {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == PAREN_TYPE
+        kind == EXP_FIELD
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -566,9 +2849,9 @@ impl AstNode for ParenType {
         &self.syntax
     }
 }
-impl AstNode for AsyncType {
+impl AstNode for ExpDecl {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == ASYNC_TYPE
+        kind == EXP_DECL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -581,9 +2864,9 @@ impl AstNode for AsyncType {
         &self.syntax
     }
 }
-impl AstNode for PathType {
+impl AstNode for ClassBody {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == PATH_TYPE
+        kind == CLASS_BODY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -596,9 +2879,54 @@ impl AstNode for PathType {
         &self.syntax
     }
 }
-impl AstNode for TupleType {
+impl AstNode for ObjectBody {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == TUPLE_TYPE
+        kind == OBJECT_BODY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for DeclField {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == DECL_FIELD
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for Visibility {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == VISIBILITY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+impl AstNode for Stability {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == STABILITY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -611,845 +2939,1263 @@ impl AstNode for TupleType {
         &self.syntax
     }
 }
-impl AstNode for ArrayType {
+impl From<LetDecl> for Decl {
+    fn from(node: LetDecl) -> Decl {
+        Decl::LetDecl(node)
+    }
+}
+impl From<VarDecl> for Decl {
+    fn from(node: VarDecl) -> Decl {
+        Decl::VarDecl(node)
+    }
+}
+impl From<TypeDecl> for Decl {
+    fn from(node: TypeDecl) -> Decl {
+        Decl::TypeDecl(node)
+    }
+}
+impl From<FuncDecl> for Decl {
+    fn from(node: FuncDecl) -> Decl {
+        Decl::FuncDecl(node)
+    }
+}
+impl From<ClassDecl> for Decl {
+    fn from(node: ClassDecl) -> Decl {
+        Decl::ClassDecl(node)
+    }
+}
+impl From<ObjectDecl> for Decl {
+    fn from(node: ObjectDecl) -> Decl {
+        Decl::ObjectDecl(node)
+    }
+}
+impl From<ExpDecl> for Decl {
+    fn from(node: ExpDecl) -> Decl {
+        Decl::ExpDecl(node)
+    }
+}
+impl AstNode for Decl {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            LET_DECL | VAR_DECL | TYPE_DECL | FUNC_DECL | CLASS_DECL | OBJECT_DECL | EXP_DECL
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            LET_DECL => Decl::LetDecl(LetDecl { syntax }),
+            VAR_DECL => Decl::VarDecl(VarDecl { syntax }),
+            TYPE_DECL => Decl::TypeDecl(TypeDecl { syntax }),
+            FUNC_DECL => Decl::FuncDecl(FuncDecl { syntax }),
+            CLASS_DECL => Decl::ClassDecl(ClassDecl { syntax }),
+            OBJECT_DECL => Decl::ObjectDecl(ObjectDecl { syntax }),
+            EXP_DECL => Decl::ExpDecl(ExpDecl { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Decl::LetDecl(it) => &it.syntax,
+            Decl::VarDecl(it) => &it.syntax,
+            Decl::TypeDecl(it) => &it.syntax,
+            Decl::FuncDecl(it) => &it.syntax,
+            Decl::ClassDecl(it) => &it.syntax,
+            Decl::ObjectDecl(it) => &it.syntax,
+            Decl::ExpDecl(it) => &it.syntax,
+        }
+    }
+}
+impl From<WildcardPat> for Pattern {
+    fn from(node: WildcardPat) -> Pattern {
+        Pattern::WildcardPat(node)
+    }
+}
+impl From<VarPat> for Pattern {
+    fn from(node: VarPat) -> Pattern {
+        Pattern::VarPat(node)
+    }
+}
+impl From<LiteralPat> for Pattern {
+    fn from(node: LiteralPat) -> Pattern {
+        Pattern::LiteralPat(node)
+    }
+}
+impl From<ParenPat> for Pattern {
+    fn from(node: ParenPat) -> Pattern {
+        Pattern::ParenPat(node)
+    }
+}
+impl From<TuplePat> for Pattern {
+    fn from(node: TuplePat) -> Pattern {
+        Pattern::TuplePat(node)
+    }
+}
+impl From<ObjectPat> for Pattern {
+    fn from(node: ObjectPat) -> Pattern {
+        Pattern::ObjectPat(node)
+    }
+}
+impl From<VariantPat> for Pattern {
+    fn from(node: VariantPat) -> Pattern {
+        Pattern::VariantPat(node)
+    }
+}
+impl From<OptionPat> for Pattern {
+    fn from(node: OptionPat) -> Pattern {
+        Pattern::OptionPat(node)
+    }
+}
+impl From<AltPat> for Pattern {
+    fn from(node: AltPat) -> Pattern {
+        Pattern::AltPat(node)
+    }
+}
+impl From<AnnotPat> for Pattern {
+    fn from(node: AnnotPat) -> Pattern {
+        Pattern::AnnotPat(node)
+    }
+}
+impl AstNode for Pattern {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            WILDCARD_PAT
+                | VAR_PAT
+                | LITERAL_PAT
+                | PAREN_PAT
+                | TUPLE_PAT
+                | OBJECT_PAT
+                | VARIANT_PAT
+                | OPTION_PAT
+                | ALT_PAT
+                | ANNOT_PAT
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            WILDCARD_PAT => Pattern::WildcardPat(WildcardPat { syntax }),
+            VAR_PAT => Pattern::VarPat(VarPat { syntax }),
+            LITERAL_PAT => Pattern::LiteralPat(LiteralPat { syntax }),
+            PAREN_PAT => Pattern::ParenPat(ParenPat { syntax }),
+            TUPLE_PAT => Pattern::TuplePat(TuplePat { syntax }),
+            OBJECT_PAT => Pattern::ObjectPat(ObjectPat { syntax }),
+            VARIANT_PAT => Pattern::VariantPat(VariantPat { syntax }),
+            OPTION_PAT => Pattern::OptionPat(OptionPat { syntax }),
+            ALT_PAT => Pattern::AltPat(AltPat { syntax }),
+            ANNOT_PAT => Pattern::AnnotPat(AnnotPat { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Pattern::WildcardPat(it) => &it.syntax,
+            Pattern::VarPat(it) => &it.syntax,
+            Pattern::LiteralPat(it) => &it.syntax,
+            Pattern::ParenPat(it) => &it.syntax,
+            Pattern::TuplePat(it) => &it.syntax,
+            Pattern::ObjectPat(it) => &it.syntax,
+            Pattern::VariantPat(it) => &it.syntax,
+            Pattern::OptionPat(it) => &it.syntax,
+            Pattern::AltPat(it) => &it.syntax,
+            Pattern::AnnotPat(it) => &it.syntax,
+        }
+    }
+}
+impl From<OptionalType> for Type {
+    fn from(node: OptionalType) -> Type {
+        Type::OptionalType(node)
+    }
+}
+impl From<ParenType> for Type {
+    fn from(node: ParenType) -> Type {
+        Type::ParenType(node)
+    }
+}
+impl From<AsyncType> for Type {
+    fn from(node: AsyncType) -> Type {
+        Type::AsyncType(node)
+    }
+}
+impl From<PathType> for Type {
+    fn from(node: PathType) -> Type {
+        Type::PathType(node)
+    }
+}
+impl From<TupleType> for Type {
+    fn from(node: TupleType) -> Type {
+        Type::TupleType(node)
+    }
+}
+impl From<ArrayType> for Type {
+    fn from(node: ArrayType) -> Type {
+        Type::ArrayType(node)
+    }
+}
+impl From<FuncType> for Type {
+    fn from(node: FuncType) -> Type {
+        Type::FuncType(node)
+    }
+}
+impl From<ObjectType> for Type {
+    fn from(node: ObjectType) -> Type {
+        Type::ObjectType(node)
+    }
+}
+impl From<VariantType> for Type {
+    fn from(node: VariantType) -> Type {
+        Type::VariantType(node)
+    }
+}
+impl From<NamedType> for Type {
+    fn from(node: NamedType) -> Type {
+        Type::NamedType(node)
+    }
+}
+impl From<PrimType> for Type {
+    fn from(node: PrimType) -> Type {
+        Type::PrimType(node)
+    }
+}
+impl From<AndType> for Type {
+    fn from(node: AndType) -> Type {
+        Type::AndType(node)
+    }
+}
+impl From<OrType> for Type {
+    fn from(node: OrType) -> Type {
+        Type::OrType(node)
+    }
+}
+impl From<AnyType> for Type {
+    fn from(node: AnyType) -> Type {
+        Type::AnyType(node)
+    }
+}
+impl From<NoneType> for Type {
+    fn from(node: NoneType) -> Type {
+        Type::NoneType(node)
+    }
+}
+impl From<NullType> for Type {
+    fn from(node: NullType) -> Type {
+        Type::NullType(node)
+    }
+}
+impl From<ActorClassType> for Type {
+    fn from(node: ActorClassType) -> Type {
+        Type::ActorClassType(node)
+    }
+}
+impl AstNode for Type {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            OPTIONAL_TYPE
+                | PAREN_TYPE
+                | ASYNC_TYPE
+                | PATH_TYPE
+                | TUPLE_TYPE
+                | ARRAY_TYPE
+                | FUNC_TYPE
+                | OBJECT_TYPE
+                | VARIANT_TYPE
+                | NAMED_TYPE
+                | PRIM_TYPE
+                | AND_TYPE
+                | OR_TYPE
+                | ANY_TYPE
+                | NONE_TYPE
+                | NULL_TYPE
+                | ACTOR_CLASS_TYPE
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            OPTIONAL_TYPE => Type::OptionalType(OptionalType { syntax }),
+            PAREN_TYPE => Type::ParenType(ParenType { syntax }),
+            ASYNC_TYPE => Type::AsyncType(AsyncType { syntax }),
+            PATH_TYPE => Type::PathType(PathType { syntax }),
+            TUPLE_TYPE => Type::TupleType(TupleType { syntax }),
+            ARRAY_TYPE => Type::ArrayType(ArrayType { syntax }),
+            FUNC_TYPE => Type::FuncType(FuncType { syntax }),
+            OBJECT_TYPE => Type::ObjectType(ObjectType { syntax }),
+            VARIANT_TYPE => Type::VariantType(VariantType { syntax }),
+            NAMED_TYPE => Type::NamedType(NamedType { syntax }),
+            PRIM_TYPE => Type::PrimType(PrimType { syntax }),
+            AND_TYPE => Type::AndType(AndType { syntax }),
+            OR_TYPE => Type::OrType(OrType { syntax }),
+            ANY_TYPE => Type::AnyType(AnyType { syntax }),
+            NONE_TYPE => Type::NoneType(NoneType { syntax }),
+            NULL_TYPE => Type::NullType(NullType { syntax }),
+            ACTOR_CLASS_TYPE => Type::ActorClassType(ActorClassType { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Type::OptionalType(it) => &it.syntax,
+            Type::ParenType(it) => &it.syntax,
+            Type::AsyncType(it) => &it.syntax,
+            Type::PathType(it) => &it.syntax,
+            Type::TupleType(it) => &it.syntax,
+            Type::ArrayType(it) => &it.syntax,
+            Type::FuncType(it) => &it.syntax,
+            Type::ObjectType(it) => &it.syntax,
+            Type::VariantType(it) => &it.syntax,
+            Type::NamedType(it) => &it.syntax,
+            Type::PrimType(it) => &it.syntax,
+            Type::AndType(it) => &it.syntax,
+            Type::OrType(it) => &it.syntax,
+            Type::AnyType(it) => &it.syntax,
+            Type::NoneType(it) => &it.syntax,
+            Type::NullType(it) => &it.syntax,
+            Type::ActorClassType(it) => &it.syntax,
+        }
+    }
+}
+impl From<TypeField> for ObjectField {
+    fn from(node: TypeField) -> ObjectField {
+        ObjectField::TypeField(node)
+    }
+}
+impl From<TypeFieldFunc> for ObjectField {
+    fn from(node: TypeFieldFunc) -> ObjectField {
+        ObjectField::TypeFieldFunc(node)
+    }
+}
+impl AstNode for ObjectField {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == ARRAY_TYPE
+        matches!(kind, TYPE_FIELD | TYPE_FIELD_FUNC)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+        let res = match syntax.kind() {
+            TYPE_FIELD => ObjectField::TypeField(TypeField { syntax }),
+            TYPE_FIELD_FUNC => ObjectField::TypeFieldFunc(TypeFieldFunc { syntax }),
+            _ => return None,
+        };
+        Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+        match self {
+            ObjectField::TypeField(it) => &it.syntax,
+            ObjectField::TypeFieldFunc(it) => &it.syntax,
+        }
     }
 }
-impl AstNode for FuncType {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == FUNC_TYPE
-    }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+impl From<PatternFieldPun> for PatternField {
+    fn from(node: PatternFieldPun) -> PatternField {
+        PatternField::PatternFieldPun(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl From<PatternFieldPat> for PatternField {
+    fn from(node: PatternFieldPat) -> PatternField {
+        PatternField::PatternFieldPat(node)
     }
 }
-impl AstNode for ObjectType {
+impl AstNode for PatternField {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == OBJECT_TYPE
+        matches!(kind, PATTERN_FIELD_PUN | PATTERN_FIELD_PAT)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+        let res = match syntax.kind() {
+            PATTERN_FIELD_PUN => PatternField::PatternFieldPun(PatternFieldPun { syntax }),
+            PATTERN_FIELD_PAT => PatternField::PatternFieldPat(PatternFieldPat { syntax }),
+            _ => return None,
+        };
+        Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+        match self {
+            PatternField::PatternFieldPun(it) => &it.syntax,
+            PatternField::PatternFieldPat(it) => &it.syntax,
+        }
     }
 }
-impl AstNode for VariantType {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == VARIANT_TYPE
+impl From<LiteralExpr> for Expr {
+    fn from(node: LiteralExpr) -> Expr {
+        Expr::LiteralExpr(node)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl From<VarExpr> for Expr {
+    fn from(node: VarExpr) -> Expr {
+        Expr::VarExpr(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl From<ParenExpr> for Expr {
+    fn from(node: ParenExpr) -> Expr {
+        Expr::ParenExpr(node)
     }
 }
-impl AstNode for NamedType {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == NAMED_TYPE
+impl From<TupleExpr> for Expr {
+    fn from(node: TupleExpr) -> Expr {
+        Expr::TupleExpr(node)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl From<PrimExpr> for Expr {
+    fn from(node: PrimExpr) -> Expr {
+        Expr::PrimExpr(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl From<ArrayExpr> for Expr {
+    fn from(node: ArrayExpr) -> Expr {
+        Expr::ArrayExpr(node)
     }
 }
-impl AstNode for PrimType {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == PRIM_TYPE
+impl From<IndexExpr> for Expr {
+    fn from(node: IndexExpr) -> Expr {
+        Expr::IndexExpr(node)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl From<ProjExpr> for Expr {
+    fn from(node: ProjExpr) -> Expr {
+        Expr::ProjExpr(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl From<DotExpr> for Expr {
+    fn from(node: DotExpr) -> Expr {
+        Expr::DotExpr(node)
     }
 }
-impl AstNode for Path {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == PATH
+impl From<CallExpr> for Expr {
+    fn from(node: CallExpr) -> Expr {
+        Expr::CallExpr(node)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl From<VariantExpr> for Expr {
+    fn from(node: VariantExpr) -> Expr {
+        Expr::VariantExpr(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl From<OptionExpr> for Expr {
+    fn from(node: OptionExpr) -> Expr {
+        Expr::OptionExpr(node)
     }
 }
-impl AstNode for TypeArgs {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == TYPE_ARGS
+impl From<UnaryExpr> for Expr {
+    fn from(node: UnaryExpr) -> Expr {
+        Expr::UnaryExpr(node)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl From<ActorUrlExpr> for Expr {
+    fn from(node: ActorUrlExpr) -> Expr {
+        Expr::ActorUrlExpr(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl From<ShowExpr> for Expr {
+    fn from(node: ShowExpr) -> Expr {
+        Expr::ShowExpr(node)
     }
 }
-impl AstNode for TypeParams {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == TYPE_PARAMS
+impl From<BinExpr> for Expr {
+    fn from(node: BinExpr) -> Expr {
+        Expr::BinExpr(node)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl From<AnnotExpr> for Expr {
+    fn from(node: AnnotExpr) -> Expr {
+        Expr::AnnotExpr(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl From<AssignExpr> for Expr {
+    fn from(node: AssignExpr) -> Expr {
+        Expr::AssignExpr(node)
     }
 }
-impl AstNode for FuncArg {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == FUNC_ARG
+impl From<ReturnExpr> for Expr {
+    fn from(node: ReturnExpr) -> Expr {
+        Expr::ReturnExpr(node)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl From<AsyncExpr> for Expr {
+    fn from(node: AsyncExpr) -> Expr {
+        Expr::AsyncExpr(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl From<AwaitExpr> for Expr {
+    fn from(node: AwaitExpr) -> Expr {
+        Expr::AwaitExpr(node)
     }
 }
-impl AstNode for FuncResult {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == FUNC_RESULT
+impl From<AssertExpr> for Expr {
+    fn from(node: AssertExpr) -> Expr {
+        Expr::AssertExpr(node)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl From<DebugExpr> for Expr {
+    fn from(node: DebugExpr) -> Expr {
+        Expr::DebugExpr(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl From<IfExpr> for Expr {
+    fn from(node: IfExpr) -> Expr {
+        Expr::IfExpr(node)
     }
 }
-impl AstNode for TypeBind {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == TYPE_BIND
+impl From<SwitchExpr> for Expr {
+    fn from(node: SwitchExpr) -> Expr {
+        Expr::SwitchExpr(node)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl From<TryExpr> for Expr {
+    fn from(node: TryExpr) -> Expr {
+        Expr::TryExpr(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl From<ThrowExpr> for Expr {
+    fn from(node: ThrowExpr) -> Expr {
+        Expr::ThrowExpr(node)
     }
 }
-impl AstNode for TypeBound {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == TYPE_BOUND
+impl From<WhileExpr> for Expr {
+    fn from(node: WhileExpr) -> Expr {
+        Expr::WhileExpr(node)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl From<LoopExpr> for Expr {
+    fn from(node: LoopExpr) -> Expr {
+        Expr::LoopExpr(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl From<ForExpr> for Expr {
+    fn from(node: ForExpr) -> Expr {
+        Expr::ForExpr(node)
     }
 }
-impl AstNode for ObjectSort {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == OBJECT_SORT
+impl From<LabelExpr> for Expr {
+    fn from(node: LabelExpr) -> Expr {
+        Expr::LabelExpr(node)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl From<BreakExpr> for Expr {
+    fn from(node: BreakExpr) -> Expr {
+        Expr::BreakExpr(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl From<ContinueExpr> for Expr {
+    fn from(node: ContinueExpr) -> Expr {
+        Expr::ContinueExpr(node)
     }
 }
-impl AstNode for TypeField {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == TYPE_FIELD
+impl From<IgnoreExpr> for Expr {
+    fn from(node: IgnoreExpr) -> Expr {
+        Expr::IgnoreExpr(node)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl From<BlockExpr> for Expr {
+    fn from(node: BlockExpr) -> Expr {
+        Expr::BlockExpr(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl From<DoExpr> for Expr {
+    fn from(node: DoExpr) -> Expr {
+        Expr::DoExpr(node)
     }
 }
-impl AstNode for TypeFieldFunc {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == TYPE_FIELD_FUNC
+impl From<ObjectExpr> for Expr {
+    fn from(node: ObjectExpr) -> Expr {
+        Expr::ObjectExpr(node)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl From<LetDecl> for Expr {
+    fn from(node: LetDecl) -> Expr {
+        Expr::LetDecl(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl From<VarDecl> for Expr {
+    fn from(node: VarDecl) -> Expr {
+        Expr::VarDecl(node)
     }
 }
-impl AstNode for TypeAnnotation {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == TYPE_ANNOTATION
+impl From<TypeDecl> for Expr {
+    fn from(node: TypeDecl) -> Expr {
+        Expr::TypeDecl(node)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl From<FuncDecl> for Expr {
+    fn from(node: FuncDecl) -> Expr {
+        Expr::FuncDecl(node)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl From<ClassDecl> for Expr {
+    fn from(node: ClassDecl) -> Expr {
+        Expr::ClassDecl(node)
     }
 }
-impl AstNode for TypeTag {
+impl From<ObjectDecl> for Expr {
+    fn from(node: ObjectDecl) -> Expr {
+        Expr::ObjectDecl(node)
+    }
+}
+impl AstNode for Expr {
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == TYPE_TAG
+        matches!(
+            kind,
+            LITERAL_EXPR
+                | VAR_EXPR
+                | PAREN_EXPR
+                | TUPLE_EXPR
+                | PRIM_EXPR
+                | ARRAY_EXPR
+                | INDEX_EXPR
+                | PROJ_EXPR
+                | DOT_EXPR
+                | CALL_EXPR
+                | VARIANT_EXPR
+                | OPTION_EXPR
+                | UNARY_EXPR
+                | ACTOR_URL_EXPR
+                | SHOW_EXPR
+                | BIN_EXPR
+                | ANNOT_EXPR
+                | ASSIGN_EXPR
+                | RETURN_EXPR
+                | ASYNC_EXPR
+                | AWAIT_EXPR
+                | ASSERT_EXPR
+                | DEBUG_EXPR
+                | IF_EXPR
+                | SWITCH_EXPR
+                | TRY_EXPR
+                | THROW_EXPR
+                | WHILE_EXPR
+                | LOOP_EXPR
+                | FOR_EXPR
+                | LABEL_EXPR
+                | BREAK_EXPR
+                | CONTINUE_EXPR
+                | IGNORE_EXPR
+                | BLOCK_EXPR
+                | DO_EXPR
+                | OBJECT_EXPR
+                | LET_DECL
+                | VAR_DECL
+                | TYPE_DECL
+                | FUNC_DECL
+                | CLASS_DECL
+                | OBJECT_DECL
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+        let res = match syntax.kind() {
+            LITERAL_EXPR => Expr::LiteralExpr(LiteralExpr { syntax }),
+            VAR_EXPR => Expr::VarExpr(VarExpr { syntax }),
+            PAREN_EXPR => Expr::ParenExpr(ParenExpr { syntax }),
+            TUPLE_EXPR => Expr::TupleExpr(TupleExpr { syntax }),
+            PRIM_EXPR => Expr::PrimExpr(PrimExpr { syntax }),
+            ARRAY_EXPR => Expr::ArrayExpr(ArrayExpr { syntax }),
+            INDEX_EXPR => Expr::IndexExpr(IndexExpr { syntax }),
+            PROJ_EXPR => Expr::ProjExpr(ProjExpr { syntax }),
+            DOT_EXPR => Expr::DotExpr(DotExpr { syntax }),
+            CALL_EXPR => Expr::CallExpr(CallExpr { syntax }),
+            VARIANT_EXPR => Expr::VariantExpr(VariantExpr { syntax }),
+            OPTION_EXPR => Expr::OptionExpr(OptionExpr { syntax }),
+            UNARY_EXPR => Expr::UnaryExpr(UnaryExpr { syntax }),
+            ACTOR_URL_EXPR => Expr::ActorUrlExpr(ActorUrlExpr { syntax }),
+            SHOW_EXPR => Expr::ShowExpr(ShowExpr { syntax }),
+            BIN_EXPR => Expr::BinExpr(BinExpr { syntax }),
+            ANNOT_EXPR => Expr::AnnotExpr(AnnotExpr { syntax }),
+            ASSIGN_EXPR => Expr::AssignExpr(AssignExpr { syntax }),
+            RETURN_EXPR => Expr::ReturnExpr(ReturnExpr { syntax }),
+            ASYNC_EXPR => Expr::AsyncExpr(AsyncExpr { syntax }),
+            AWAIT_EXPR => Expr::AwaitExpr(AwaitExpr { syntax }),
+            ASSERT_EXPR => Expr::AssertExpr(AssertExpr { syntax }),
+            DEBUG_EXPR => Expr::DebugExpr(DebugExpr { syntax }),
+            IF_EXPR => Expr::IfExpr(IfExpr { syntax }),
+            SWITCH_EXPR => Expr::SwitchExpr(SwitchExpr { syntax }),
+            TRY_EXPR => Expr::TryExpr(TryExpr { syntax }),
+            THROW_EXPR => Expr::ThrowExpr(ThrowExpr { syntax }),
+            WHILE_EXPR => Expr::WhileExpr(WhileExpr { syntax }),
+            LOOP_EXPR => Expr::LoopExpr(LoopExpr { syntax }),
+            FOR_EXPR => Expr::ForExpr(ForExpr { syntax }),
+            LABEL_EXPR => Expr::LabelExpr(LabelExpr { syntax }),
+            BREAK_EXPR => Expr::BreakExpr(BreakExpr { syntax }),
+            CONTINUE_EXPR => Expr::ContinueExpr(ContinueExpr { syntax }),
+            IGNORE_EXPR => Expr::IgnoreExpr(IgnoreExpr { syntax }),
+            BLOCK_EXPR => Expr::BlockExpr(BlockExpr { syntax }),
+            DO_EXPR => Expr::DoExpr(DoExpr { syntax }),
+            OBJECT_EXPR => Expr::ObjectExpr(ObjectExpr { syntax }),
+            LET_DECL => Expr::LetDecl(LetDecl { syntax }),
+            VAR_DECL => Expr::VarDecl(VarDecl { syntax }),
+            TYPE_DECL => Expr::TypeDecl(TypeDecl { syntax }),
+            FUNC_DECL => Expr::FuncDecl(FuncDecl { syntax }),
+            CLASS_DECL => Expr::ClassDecl(ClassDecl { syntax }),
+            OBJECT_DECL => Expr::ObjectDecl(ObjectDecl { syntax }),
+            _ => return None,
+        };
+        Some(res)
     }
     fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+        match self {
+            Expr::LiteralExpr(it) => &it.syntax,
+            Expr::VarExpr(it) => &it.syntax,
+            Expr::ParenExpr(it) => &it.syntax,
+            Expr::TupleExpr(it) => &it.syntax,
+            Expr::PrimExpr(it) => &it.syntax,
+            Expr::ArrayExpr(it) => &it.syntax,
+            Expr::IndexExpr(it) => &it.syntax,
+            Expr::ProjExpr(it) => &it.syntax,
+            Expr::DotExpr(it) => &it.syntax,
+            Expr::CallExpr(it) => &it.syntax,
+            Expr::VariantExpr(it) => &it.syntax,
+            Expr::OptionExpr(it) => &it.syntax,
+            Expr::UnaryExpr(it) => &it.syntax,
+            Expr::ActorUrlExpr(it) => &it.syntax,
+            Expr::ShowExpr(it) => &it.syntax,
+            Expr::BinExpr(it) => &it.syntax,
+            Expr::AnnotExpr(it) => &it.syntax,
+            Expr::AssignExpr(it) => &it.syntax,
+            Expr::ReturnExpr(it) => &it.syntax,
+            Expr::AsyncExpr(it) => &it.syntax,
+            Expr::AwaitExpr(it) => &it.syntax,
+            Expr::AssertExpr(it) => &it.syntax,
+            Expr::DebugExpr(it) => &it.syntax,
+            Expr::IfExpr(it) => &it.syntax,
+            Expr::SwitchExpr(it) => &it.syntax,
+            Expr::TryExpr(it) => &it.syntax,
+            Expr::ThrowExpr(it) => &it.syntax,
+            Expr::WhileExpr(it) => &it.syntax,
+            Expr::LoopExpr(it) => &it.syntax,
+            Expr::ForExpr(it) => &it.syntax,
+            Expr::LabelExpr(it) => &it.syntax,
+            Expr::BreakExpr(it) => &it.syntax,
+            Expr::ContinueExpr(it) => &it.syntax,
+            Expr::IgnoreExpr(it) => &it.syntax,
+            Expr::BlockExpr(it) => &it.syntax,
+            Expr::DoExpr(it) => &it.syntax,
+            Expr::ObjectExpr(it) => &it.syntax,
+            Expr::LetDecl(it) => &it.syntax,
+            Expr::VarDecl(it) => &it.syntax,
+            Expr::TypeDecl(it) => &it.syntax,
+            Expr::FuncDecl(it) => &it.syntax,
+            Expr::ClassDecl(it) => &it.syntax,
+            Expr::ObjectDecl(it) => &it.syntax,
+        }
     }
 }
-impl AstNode for WildcardPat {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == WILDCARD_PAT
+impl std::fmt::Display for Decl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl std::fmt::Display for Pattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl AstNode for VarPat {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == VAR_PAT
+impl std::fmt::Display for ObjectField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl std::fmt::Display for PatternField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl std::fmt::Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl AstNode for LiteralPat {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == LITERAL_PAT
+impl std::fmt::Display for SourceFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl std::fmt::Display for ImportDecl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl std::fmt::Display for Literal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl AstNode for ParenPat {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == PAREN_PAT
+impl std::fmt::Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl std::fmt::Display for OptionalType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl std::fmt::Display for ParenType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl AstNode for TuplePat {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == TUPLE_PAT
+impl std::fmt::Display for AsyncType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl std::fmt::Display for PathType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl std::fmt::Display for TupleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl AstNode for ObjectPat {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == OBJECT_PAT
+impl std::fmt::Display for ArrayType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl std::fmt::Display for FuncType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl std::fmt::Display for ObjectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl AstNode for VariantPat {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == VARIANT_PAT
+impl std::fmt::Display for VariantType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl std::fmt::Display for NamedType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl std::fmt::Display for PrimType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl AstNode for PatternFieldPun {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == PATTERN_FIELD_PUN
+impl std::fmt::Display for AndType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl std::fmt::Display for OrType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl std::fmt::Display for AnyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl AstNode for PatternFieldPat {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == PATTERN_FIELD_PAT
+impl std::fmt::Display for NoneType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl std::fmt::Display for NullType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl std::fmt::Display for ActorClassType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl AstNode for Tag {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == TAG
+impl std::fmt::Display for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
-        }
+}
+impl std::fmt::Display for TypeArgs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+}
+impl std::fmt::Display for FuncSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<OptionalType> for Type {
-    fn from(node: OptionalType) -> Type {
-        Type::OptionalType(node)
+impl std::fmt::Display for TypeParams {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<ParenType> for Type {
-    fn from(node: ParenType) -> Type {
-        Type::ParenType(node)
+impl std::fmt::Display for FuncArg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<AsyncType> for Type {
-    fn from(node: AsyncType) -> Type {
-        Type::AsyncType(node)
+impl std::fmt::Display for FuncResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<PathType> for Type {
-    fn from(node: PathType) -> Type {
-        Type::PathType(node)
+impl std::fmt::Display for TypeBind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<TupleType> for Type {
-    fn from(node: TupleType) -> Type {
-        Type::TupleType(node)
+impl std::fmt::Display for TypeBound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ObjectSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TypeField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TypeFieldFunc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<ArrayType> for Type {
-    fn from(node: ArrayType) -> Type {
-        Type::ArrayType(node)
+impl std::fmt::Display for TypeAnnotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<FuncType> for Type {
-    fn from(node: FuncType) -> Type {
-        Type::FuncType(node)
+impl std::fmt::Display for TypeTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<ObjectType> for Type {
-    fn from(node: ObjectType) -> Type {
-        Type::ObjectType(node)
+impl std::fmt::Display for WildcardPat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<VariantType> for Type {
-    fn from(node: VariantType) -> Type {
-        Type::VariantType(node)
+impl std::fmt::Display for VarPat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<NamedType> for Type {
-    fn from(node: NamedType) -> Type {
-        Type::NamedType(node)
+impl std::fmt::Display for LiteralPat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<PrimType> for Type {
-    fn from(node: PrimType) -> Type {
-        Type::PrimType(node)
+impl std::fmt::Display for ParenPat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl AstNode for Type {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        match kind {
-            OPTIONAL_TYPE | PAREN_TYPE | ASYNC_TYPE | PATH_TYPE | TUPLE_TYPE | ARRAY_TYPE
-            | FUNC_TYPE | OBJECT_TYPE | VARIANT_TYPE | NAMED_TYPE | PRIM_TYPE => true,
-            _ => false,
-        }
-    }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let res = match syntax.kind() {
-            OPTIONAL_TYPE => Type::OptionalType(OptionalType { syntax }),
-            PAREN_TYPE => Type::ParenType(ParenType { syntax }),
-            ASYNC_TYPE => Type::AsyncType(AsyncType { syntax }),
-            PATH_TYPE => Type::PathType(PathType { syntax }),
-            TUPLE_TYPE => Type::TupleType(TupleType { syntax }),
-            ARRAY_TYPE => Type::ArrayType(ArrayType { syntax }),
-            FUNC_TYPE => Type::FuncType(FuncType { syntax }),
-            OBJECT_TYPE => Type::ObjectType(ObjectType { syntax }),
-            VARIANT_TYPE => Type::VariantType(VariantType { syntax }),
-            NAMED_TYPE => Type::NamedType(NamedType { syntax }),
-            PRIM_TYPE => Type::PrimType(PrimType { syntax }),
-            _ => return None,
-        };
-        Some(res)
+impl std::fmt::Display for TuplePat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        match self {
-            Type::OptionalType(it) => &it.syntax,
-            Type::ParenType(it) => &it.syntax,
-            Type::AsyncType(it) => &it.syntax,
-            Type::PathType(it) => &it.syntax,
-            Type::TupleType(it) => &it.syntax,
-            Type::ArrayType(it) => &it.syntax,
-            Type::FuncType(it) => &it.syntax,
-            Type::ObjectType(it) => &it.syntax,
-            Type::VariantType(it) => &it.syntax,
-            Type::NamedType(it) => &it.syntax,
-            Type::PrimType(it) => &it.syntax,
-        }
+}
+impl std::fmt::Display for ObjectPat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<TypeField> for ObjectField {
-    fn from(node: TypeField) -> ObjectField {
-        ObjectField::TypeField(node)
+impl std::fmt::Display for VariantPat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<TypeFieldFunc> for ObjectField {
-    fn from(node: TypeFieldFunc) -> ObjectField {
-        ObjectField::TypeFieldFunc(node)
+impl std::fmt::Display for OptionPat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl AstNode for ObjectField {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        match kind {
-            TYPE_FIELD | TYPE_FIELD_FUNC => true,
-            _ => false,
-        }
+impl std::fmt::Display for AltPat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let res = match syntax.kind() {
-            TYPE_FIELD => ObjectField::TypeField(TypeField { syntax }),
-            TYPE_FIELD_FUNC => ObjectField::TypeFieldFunc(TypeFieldFunc { syntax }),
-            _ => return None,
-        };
-        Some(res)
+}
+impl std::fmt::Display for AnnotPat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        match self {
-            ObjectField::TypeField(it) => &it.syntax,
-            ObjectField::TypeFieldFunc(it) => &it.syntax,
-        }
+}
+impl std::fmt::Display for PatternFieldPun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<WildcardPat> for Pattern {
-    fn from(node: WildcardPat) -> Pattern {
-        Pattern::WildcardPat(node)
+impl std::fmt::Display for PatternFieldPat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<VarPat> for Pattern {
-    fn from(node: VarPat) -> Pattern {
-        Pattern::VarPat(node)
+impl std::fmt::Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<LiteralPat> for Pattern {
-    fn from(node: LiteralPat) -> Pattern {
-        Pattern::LiteralPat(node)
+impl std::fmt::Display for LiteralExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<ParenPat> for Pattern {
-    fn from(node: ParenPat) -> Pattern {
-        Pattern::ParenPat(node)
+impl std::fmt::Display for VarExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<TuplePat> for Pattern {
-    fn from(node: TuplePat) -> Pattern {
-        Pattern::TuplePat(node)
+impl std::fmt::Display for ParenExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<ObjectPat> for Pattern {
-    fn from(node: ObjectPat) -> Pattern {
-        Pattern::ObjectPat(node)
+impl std::fmt::Display for TupleExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<VariantPat> for Pattern {
-    fn from(node: VariantPat) -> Pattern {
-        Pattern::VariantPat(node)
+impl std::fmt::Display for PrimExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl AstNode for Pattern {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        match kind {
-            WILDCARD_PAT | VAR_PAT | LITERAL_PAT | PAREN_PAT | TUPLE_PAT | OBJECT_PAT
-            | VARIANT_PAT => true,
-            _ => false,
-        }
+impl std::fmt::Display for ArrayExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let res = match syntax.kind() {
-            WILDCARD_PAT => Pattern::WildcardPat(WildcardPat { syntax }),
-            VAR_PAT => Pattern::VarPat(VarPat { syntax }),
-            LITERAL_PAT => Pattern::LiteralPat(LiteralPat { syntax }),
-            PAREN_PAT => Pattern::ParenPat(ParenPat { syntax }),
-            TUPLE_PAT => Pattern::TuplePat(TuplePat { syntax }),
-            OBJECT_PAT => Pattern::ObjectPat(ObjectPat { syntax }),
-            VARIANT_PAT => Pattern::VariantPat(VariantPat { syntax }),
-            _ => return None,
-        };
-        Some(res)
+}
+impl std::fmt::Display for IndexExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        match self {
-            Pattern::WildcardPat(it) => &it.syntax,
-            Pattern::VarPat(it) => &it.syntax,
-            Pattern::LiteralPat(it) => &it.syntax,
-            Pattern::ParenPat(it) => &it.syntax,
-            Pattern::TuplePat(it) => &it.syntax,
-            Pattern::ObjectPat(it) => &it.syntax,
-            Pattern::VariantPat(it) => &it.syntax,
-        }
+}
+impl std::fmt::Display for ProjExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<PatternFieldPun> for PatternField {
-    fn from(node: PatternFieldPun) -> PatternField {
-        PatternField::PatternFieldPun(node)
+impl std::fmt::Display for DotExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl From<PatternFieldPat> for PatternField {
-    fn from(node: PatternFieldPat) -> PatternField {
-        PatternField::PatternFieldPat(node)
+impl std::fmt::Display for CallExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl AstNode for PatternField {
-    fn can_cast(kind: SyntaxKind) -> bool {
-        match kind {
-            PATTERN_FIELD_PUN | PATTERN_FIELD_PAT => true,
-            _ => false,
-        }
+impl std::fmt::Display for VariantExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let res = match syntax.kind() {
-            PATTERN_FIELD_PUN => PatternField::PatternFieldPun(PatternFieldPun { syntax }),
-            PATTERN_FIELD_PAT => PatternField::PatternFieldPat(PatternFieldPat { syntax }),
-            _ => return None,
-        };
-        Some(res)
+}
+impl std::fmt::Display for OptionExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
-    fn syntax(&self) -> &SyntaxNode {
-        match self {
-            PatternField::PatternFieldPun(it) => &it.syntax,
-            PatternField::PatternFieldPat(it) => &it.syntax,
-        }
+}
+impl std::fmt::Display for UnaryExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for Type {
+impl std::fmt::Display for ActorUrlExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for ObjectField {
+impl std::fmt::Display for ShowExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for Pattern {
+impl std::fmt::Display for BinExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for PatternField {
+impl std::fmt::Display for AnnotExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for Name {
+impl std::fmt::Display for AssignExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for Literal {
+impl std::fmt::Display for ReturnExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for OptionalType {
+impl std::fmt::Display for AsyncExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for ParenType {
+impl std::fmt::Display for AwaitExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for AsyncType {
+impl std::fmt::Display for AssertExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for PathType {
+impl std::fmt::Display for DebugExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for TupleType {
+impl std::fmt::Display for IfExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for ArrayType {
+impl std::fmt::Display for SwitchExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for FuncType {
+impl std::fmt::Display for TryExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for ObjectType {
+impl std::fmt::Display for ThrowExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for VariantType {
+impl std::fmt::Display for WhileExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for NamedType {
+impl std::fmt::Display for LoopExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for PrimType {
+impl std::fmt::Display for ForExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for Path {
+impl std::fmt::Display for LabelExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for TypeArgs {
+impl std::fmt::Display for BreakExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for TypeParams {
+impl std::fmt::Display for ContinueExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for FuncArg {
+impl std::fmt::Display for IgnoreExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for FuncResult {
+impl std::fmt::Display for BlockExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for TypeBind {
+impl std::fmt::Display for DoExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for TypeBound {
+impl std::fmt::Display for ObjectExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for ObjectSort {
+impl std::fmt::Display for LetDecl {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for TypeField {
+impl std::fmt::Display for VarDecl {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for TypeFieldFunc {
+impl std::fmt::Display for TypeDecl {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for TypeAnnotation {
+impl std::fmt::Display for FuncDecl {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for TypeTag {
+impl std::fmt::Display for ClassDecl {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for WildcardPat {
+impl std::fmt::Display for ObjectDecl {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for VarPat {
+impl std::fmt::Display for CaseArm {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for LiteralPat {
+impl std::fmt::Display for CatchClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for ParenPat {
+impl std::fmt::Display for ExpField {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for TuplePat {
+impl std::fmt::Display for ExpDecl {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for ObjectPat {
+impl std::fmt::Display for ClassBody {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for VariantPat {
+impl std::fmt::Display for ObjectBody {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for PatternFieldPun {
+impl std::fmt::Display for DeclField {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for PatternFieldPat {
+impl std::fmt::Display for Visibility {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
-impl std::fmt::Display for Tag {
+impl std::fmt::Display for Stability {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
@@ -0,0 +1,207 @@
+//! Decoding of numeric literals.
+//!
+//! The lexer is lenient about underscores and missing digits, so malformed
+//! literals are only reported once they are decoded.
+
+pub use num_bigint::BigUint;
+use rowan::{TextRange, TextSize};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NumberErrorKind {
+    /// An underscore that doesn't sit between two digits, like in `1__000` or `0x_FF`
+    MisplacedUnderscore,
+    /// A literal without digits where some are required, like `0x`
+    MissingDigits,
+    /// A float literal too large to be represented as an `f64`
+    FloatOutOfRange,
+}
+
+/// A malformed numeric literal, with its range relative to the start of the
+/// literal.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NumberError {
+    pub kind: NumberErrorKind,
+    pub range: TextRange,
+}
+
+impl NumberError {
+    fn new(kind: NumberErrorKind, start: usize, end: usize) -> NumberError {
+        NumberError {
+            kind,
+            range: TextRange::new(offset(start), offset(end)),
+        }
+    }
+}
+
+fn offset(n: usize) -> TextSize {
+    TextSize::from(n as u32)
+}
+
+/// Decodes a natural number literal like `1_000` or `0xFF_FF`.
+pub fn parse_nat(literal: &str) -> Result<BigUint, NumberError> {
+    let (radix, start) = if literal.starts_with("0x") {
+        (16, 2)
+    } else {
+        (10, 0)
+    };
+    let digits = digits(literal, start, literal.len(), radix)?;
+    BigUint::parse_bytes(digits.as_bytes(), radix)
+        .ok_or_else(|| NumberError::new(NumberErrorKind::MissingDigits, 0, literal.len()))
+}
+
+/// Decodes a float literal like `3.14`, `1e10` or the hex float `0x1.8p3`.
+pub fn parse_float(literal: &str) -> Result<f64, NumberError> {
+    let hex = literal.starts_with("0x");
+    let (radix, start, exponent_chars) = if hex {
+        (16, 2, ['p', 'P'])
+    } else {
+        (10, 0, ['e', 'E'])
+    };
+    let exponent_start = literal.find(exponent_chars);
+    let mantissa_end = exponent_start.unwrap_or(literal.len());
+    let (int_end, frac) = match literal[..mantissa_end].find('.') {
+        Some(dot) => (dot, Some(dot + 1)),
+        None => (mantissa_end, None),
+    };
+
+    let int = digits(literal, start, int_end, radix)?;
+    let frac = match frac {
+        // A fraction may be left out entirely, as in `1.`
+        Some(frac) if frac < mantissa_end => digits(literal, frac, mantissa_end, radix)?,
+        _ => String::new(),
+    };
+    let exponent = match exponent_start {
+        Some(e) => {
+            let sign_len = match literal[e + 1..].chars().next() {
+                Some('+') | Some('-') => 1,
+                _ => 0,
+            };
+            let digits = digits(literal, e + 1 + sign_len, literal.len(), 10)?;
+            let negative = literal[e + 1..].starts_with('-');
+            // Exponents too large for an i32 over- or underflow the float anyway
+            let magnitude = digits.parse::<i32>().unwrap_or(i32::MAX);
+            if negative {
+                -magnitude
+            } else {
+                magnitude
+            }
+        }
+        None => 0,
+    };
+
+    let value = if hex {
+        hex_float(&int, &frac, exponent)
+    } else {
+        // Rust's float parser handles the rounding for us
+        format!(
+            "{}.{}e{}",
+            int,
+            if frac.is_empty() { "0" } else { &frac },
+            exponent
+        )
+        .parse::<f64>()
+        .unwrap_or(f64::INFINITY)
+    };
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(NumberError::new(
+            NumberErrorKind::FloatOutOfRange,
+            0,
+            literal.len(),
+        ))
+    }
+}
+
+/// Hex floats are exact up to the final rounding, so we collect the digits
+/// in an integer and round once when building the `f64`.
+fn hex_float(int: &str, frac: &str, exponent: i32) -> f64 {
+    // The value is `mantissa * 2^exponent`, plus something below the last
+    // bit of `mantissa` if `sticky` is set
+    let mut mantissa: u64 = 0;
+    let mut exponent = i64::from(exponent);
+    let mut sticky = false;
+    for (i, c) in int.chars().chain(frac.chars()).enumerate() {
+        let d = c.to_digit(16).unwrap_or(0);
+        let in_frac = i >= int.len();
+        if mantissa >> 60 == 0 {
+            mantissa = mantissa << 4 | u64::from(d);
+            if in_frac {
+                exponent -= 4;
+            }
+        } else {
+            sticky |= d != 0;
+            if !in_frac {
+                exponent += 4;
+            }
+        }
+    }
+    if mantissa == 0 {
+        return 0.0;
+    }
+
+    // Keep 53 significant bits, or fewer if the result is subnormal
+    let bits = i64::from(64 - mantissa.leading_zeros());
+    let shift = (bits - 53).max(-1074 - exponent);
+    let mut significand = if shift <= 0 {
+        mantissa << -shift
+    } else {
+        let shift = shift.min(100) as u32;
+        let mantissa = u128::from(mantissa);
+        let kept = mantissa >> shift;
+        let half = mantissa >> (shift - 1) & 1 == 1;
+        let rest = sticky || mantissa & ((1 << (shift - 1)) - 1) != 0;
+        // Round to nearest, ties to even
+        (kept + u128::from(half && (rest || kept & 1 == 1))) as u64
+    };
+    let mut exponent = exponent + shift;
+    if significand == 1 << 53 {
+        significand >>= 1;
+        exponent += 1;
+    }
+
+    if significand < 1 << 52 {
+        // Subnormal (or zero), the exponent is the minimum
+        return f64::from_bits(significand);
+    }
+    let biased = exponent + 52 + 1023;
+    if biased > 2046 {
+        return f64::INFINITY;
+    }
+    f64::from_bits((biased as u64) << 52 | (significand & ((1 << 52) - 1)))
+}
+
+/// Checks the digits in `literal[start..end]` and returns them without
+/// underscores. Underscores may only separate two digits.
+fn digits(literal: &str, start: usize, end: usize, radix: u32) -> Result<String, NumberError> {
+    let mut res = String::new();
+    let mut previous_digit = false;
+    for (i, c) in literal[start..end].char_indices() {
+        let i = start + i;
+        if c == '_' {
+            let next_digit = literal[i + 1..end]
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_digit(radix));
+            if !previous_digit || !next_digit {
+                return Err(NumberError::new(
+                    NumberErrorKind::MisplacedUnderscore,
+                    i,
+                    i + 1,
+                ));
+            }
+            previous_digit = false;
+        } else {
+            res.push(c);
+            previous_digit = true;
+        }
+    }
+    if res.is_empty() {
+        return Err(NumberError::new(
+            NumberErrorKind::MissingDigits,
+            0,
+            literal.len(),
+        ));
+    }
+    Ok(res)
+}
@@ -0,0 +1,224 @@
+//! Decoding of text and character literals.
+//!
+//! The lexer accepts any backslash sequence inside a literal, so malformed
+//! escapes are only reported once the literal is decoded.
+
+use rowan::{TextRange, TextSize};
+use std::str::CharIndices;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EscapeErrorKind {
+    /// A backslash followed by a character that doesn't start an escape, like `\q`
+    UnknownEscape,
+    /// A backslash right before the closing quote
+    LoneBackslash,
+    /// A byte escape that isn't followed by two hex digits, like `\4`
+    InvalidByteEscape,
+    /// A `\u` that isn't followed by `{hexdigits}`
+    MalformedUnicodeEscape,
+    /// A `\u{...}` escape naming a surrogate or a value above `10FFFF`
+    InvalidCodePoint,
+    /// Byte escapes that don't decode to valid UTF-8
+    InvalidUtf8,
+    /// A character literal that doesn't contain exactly one character
+    NotOneChar,
+}
+
+/// A malformed escape, with its range relative to the start of the literal
+/// (including the opening quote).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct EscapeError {
+    pub kind: EscapeErrorKind,
+    pub range: TextRange,
+}
+
+impl EscapeError {
+    fn new(kind: EscapeErrorKind, start: usize, end: usize) -> EscapeError {
+        EscapeError {
+            kind,
+            range: TextRange::new(offset(start), offset(end)),
+        }
+    }
+}
+
+fn offset(n: usize) -> TextSize {
+    TextSize::from(n as u32)
+}
+
+/// Decodes a text literal, as written in the source including its quotes.
+pub fn unescape_text(literal: &str) -> Result<String, Vec<EscapeError>> {
+    let mut errors = vec![];
+    let bytes = unescape_bytes(literal, &mut errors);
+    let text = bytes.into_text(literal, &mut errors);
+    if errors.is_empty() {
+        Ok(text)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Decodes a character literal, as written in the source including its quotes.
+pub fn unescape_char(literal: &str) -> Result<char, Vec<EscapeError>> {
+    let mut errors = vec![];
+    let bytes = unescape_bytes(literal, &mut errors);
+    let text = bytes.into_text(literal, &mut errors);
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(vec![EscapeError::new(
+            EscapeErrorKind::NotOneChar,
+            0,
+            literal.len(),
+        )]),
+    }
+}
+
+/// The decoded bytes of a literal. Byte escapes can produce invalid UTF-8, so
+/// we remember where each of them came from to point at the culprit.
+struct Bytes {
+    bytes: Vec<u8>,
+    byte_escapes: Vec<(usize, TextRange)>,
+}
+
+impl Bytes {
+    fn into_text(self, literal: &str, errors: &mut Vec<EscapeError>) -> String {
+        match String::from_utf8(self.bytes) {
+            Ok(text) => text,
+            Err(err) => {
+                let valid_up_to = err.utf8_error().valid_up_to();
+                let range = self
+                    .byte_escapes
+                    .iter()
+                    .find(|(pos, _)| *pos >= valid_up_to)
+                    .map(|(_, range)| *range)
+                    .unwrap_or_else(|| TextRange::new(offset(0), offset(literal.len())));
+                errors.push(EscapeError {
+                    kind: EscapeErrorKind::InvalidUtf8,
+                    range,
+                });
+                String::new()
+            }
+        }
+    }
+}
+
+fn unescape_bytes(literal: &str, errors: &mut Vec<EscapeError>) -> Bytes {
+    let mut res = Bytes {
+        bytes: vec![],
+        byte_escapes: vec![],
+    };
+    // Skip the quotes
+    let body = literal
+        .get(1..literal.len().saturating_sub(1))
+        .unwrap_or_default();
+    let mut chars = body.char_indices();
+    let mut buf = [0; 4];
+    while let Some((start, c)) = chars.next() {
+        if c != '\\' {
+            res.bytes
+                .extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+            continue;
+        }
+        // Offsets are relative to the literal, so account for the opening quote
+        let start = start + 1;
+        let escaped = match chars.next() {
+            Some((_, c)) => c,
+            None => {
+                errors.push(EscapeError::new(
+                    EscapeErrorKind::LoneBackslash,
+                    start,
+                    start + 1,
+                ));
+                break;
+            }
+        };
+        let simple = match escaped {
+            'n' => Some('\n'),
+            'r' => Some('\r'),
+            't' => Some('\t'),
+            '\\' => Some('\\'),
+            '\'' => Some('\''),
+            '"' => Some('"'),
+            _ => None,
+        };
+        if let Some(c) = simple {
+            res.bytes
+                .extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+        } else if escaped == 'u' {
+            match unicode_escape(&mut chars) {
+                Ok(c) => res
+                    .bytes
+                    .extend_from_slice(c.encode_utf8(&mut buf).as_bytes()),
+                Err(kind) => {
+                    let end = chars.offset() + 1;
+                    errors.push(EscapeError::new(kind, start, end))
+                }
+            }
+        } else if escaped.is_ascii_hexdigit() {
+            let low = chars.clone().next().and_then(|(_, c)| c.to_digit(16));
+            match low {
+                Some(low) => {
+                    chars.next();
+                    let high = escaped.to_digit(16).unwrap_or(0);
+                    let range = TextRange::new(offset(start), offset(start + 3));
+                    res.byte_escapes.push((res.bytes.len(), range));
+                    res.bytes.push((high * 16 + low) as u8);
+                }
+                None => errors.push(EscapeError::new(
+                    EscapeErrorKind::InvalidByteEscape,
+                    start,
+                    start + 2,
+                )),
+            }
+        } else {
+            errors.push(EscapeError::new(
+                EscapeErrorKind::UnknownEscape,
+                start,
+                start + 1 + escaped.len_utf8(),
+            ));
+        }
+    }
+    res
+}
+
+/// Decodes the `{1F600}` part of a `\u{1F600}` escape. Like numeric literals
+/// the digits may be separated by underscores.
+fn unicode_escape(chars: &mut CharIndices) -> Result<char, EscapeErrorKind> {
+    if !matches!(chars.clone().next(), Some((_, '{'))) {
+        return Err(EscapeErrorKind::MalformedUnicodeEscape);
+    }
+    chars.next();
+    let mut value: u32 = 0;
+    let mut digits = 0;
+    let mut overflowed = false;
+    loop {
+        match chars.clone().next() {
+            Some((_, '}')) if digits > 0 => {
+                chars.next();
+                break;
+            }
+            Some((_, '_')) if digits > 0 => {
+                chars.next();
+            }
+            Some((_, c)) if c.is_ascii_hexdigit() => {
+                chars.next();
+                digits += 1;
+                match value
+                    .checked_mul(16)
+                    .and_then(|v| v.checked_add(c.to_digit(16).unwrap_or(0)))
+                {
+                    Some(v) => value = v,
+                    None => overflowed = true,
+                }
+            }
+            _ => return Err(EscapeErrorKind::MalformedUnicodeEscape),
+        }
+    }
+    if overflowed {
+        return Err(EscapeErrorKind::InvalidCodePoint);
+    }
+    char::from_u32(value).ok_or(EscapeErrorKind::InvalidCodePoint)
+}
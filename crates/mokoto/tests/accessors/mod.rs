@@ -0,0 +1,509 @@
+//! Generated by `cargo xtask codegen`, do not edit by hand.
+
+use mokoto::syntax::{
+    ast::{self, AstNode},
+    nodes::*,
+    SyntaxNode,
+};
+/// Calls every generated accessor of `node`, whatever kind it is
+pub fn call_all(node: &SyntaxNode) {
+    if let Some(it) = SourceFile::cast(node.clone()) {
+        let _ = it.imports().count();
+        let _ = it.decls().count();
+    }
+    if let Some(it) = ImportDecl::cast(node.clone()) {
+        let _ = it.import_token();
+        let _ = it.pattern();
+        let _ = it.eq_token();
+        let _ = it.literal();
+        let _ = ast::HasDocComments::doc_comments(&it);
+    }
+    if let Some(it) = Literal::cast(node.clone()) {
+        let _ = it.nat_lit_token();
+        let _ = it.nat_lit();
+        let _ = it.float_lit_token();
+        let _ = it.float_lit();
+        let _ = it.text_lit_token();
+        let _ = it.text_lit();
+        let _ = it.char_lit_token();
+        let _ = it.char_lit();
+        let _ = it.true_token();
+        let _ = it.false_token();
+        let _ = it.null_token();
+    }
+    if let Some(it) = Name::cast(node.clone()) {
+        let _ = it.ident_token();
+        let _ = it.ident();
+    }
+    if let Some(it) = OptionalType::cast(node.clone()) {
+        let _ = it.question_mark_token();
+        let _ = it.ty();
+    }
+    if let Some(it) = ParenType::cast(node.clone()) {
+        let _ = it.l_paren_token();
+        let _ = it.ty();
+        let _ = it.r_paren_token();
+    }
+    if let Some(it) = AsyncType::cast(node.clone()) {
+        let _ = it.async_token();
+        let _ = it.async_star_token();
+        let _ = it.ty();
+    }
+    if let Some(it) = PathType::cast(node.clone()) {
+        let _ = it.path();
+        let _ = it.type_args();
+    }
+    if let Some(it) = TupleType::cast(node.clone()) {
+        let _ = it.l_paren_token();
+        let _ = it.fields().count();
+        let _ = it.r_paren_token();
+    }
+    if let Some(it) = ArrayType::cast(node.clone()) {
+        let _ = it.l_brack_token();
+        let _ = it.var_token();
+        let _ = it.ty();
+        let _ = it.r_brack_token();
+    }
+    if let Some(it) = FuncType::cast(node.clone()) {
+        let _ = it.func_sort();
+        let _ = it.func_arg();
+        let _ = it.arrow_token();
+        let _ = it.func_result();
+        let _ = ast::HasTypeParams::type_params(&it);
+    }
+    if let Some(it) = ObjectType::cast(node.clone()) {
+        let _ = it.object_sort();
+        let _ = it.l_brace_token();
+        let _ = it.fields().count();
+        let _ = it.r_brace_token();
+    }
+    if let Some(it) = VariantType::cast(node.clone()) {
+        let _ = it.l_brace_token();
+        let _ = it.hash_token();
+        let _ = it.variants().count();
+        let _ = it.r_brace_token();
+    }
+    if let Some(it) = NamedType::cast(node.clone()) {
+        let _ = it.colon_token();
+        let _ = it.ty();
+        let _ = ast::HasName::name(&it);
+    }
+    if let Some(it) = PrimType::cast(node.clone()) {
+        let _ = it.prim_token();
+        let _ = it.literal();
+    }
+    if let Some(it) = AndType::cast(node.clone()) {
+        let _ = it.and_token();
+    }
+    if let Some(it) = OrType::cast(node.clone()) {
+        let _ = it.or_token();
+    }
+    if let Some(it) = AnyType::cast(node.clone()) {
+        let _ = it.ident_token();
+        let _ = it.ident();
+    }
+    if let Some(it) = NoneType::cast(node.clone()) {
+        let _ = it.ident_token();
+        let _ = it.ident();
+    }
+    if let Some(it) = NullType::cast(node.clone()) {
+        let _ = it.ident_token();
+        let _ = it.ident();
+    }
+    if let Some(it) = ActorClassType::cast(node.clone()) {
+        let _ = it.actor_token();
+        let _ = it.class_token();
+        let _ = it.func_arg();
+        let _ = it.arrow_token();
+        let _ = it.func_result();
+        let _ = ast::HasTypeParams::type_params(&it);
+    }
+    if let Some(it) = Path::cast(node.clone()) {
+        let _ = it.ident_token();
+        let _ = it.ident();
+        let _ = it.dot_token();
+    }
+    if let Some(it) = TypeArgs::cast(node.clone()) {
+        let _ = it.l_angle_token();
+        let _ = it.args().count();
+        let _ = it.r_angle_token();
+    }
+    if let Some(it) = FuncSort::cast(node.clone()) {
+        let _ = it.shared_token();
+        let _ = it.query_token();
+        let _ = it.caller();
+    }
+    if let Some(it) = TypeParams::cast(node.clone()) {
+        let _ = it.l_angle_token();
+        let _ = it.params().count();
+        let _ = it.r_angle_token();
+    }
+    if let Some(it) = FuncArg::cast(node.clone()) {
+        let _ = it.ty();
+    }
+    if let Some(it) = FuncResult::cast(node.clone()) {
+        let _ = it.ty();
+    }
+    if let Some(it) = TypeBind::cast(node.clone()) {
+        let _ = it.type_bound();
+        let _ = ast::HasName::name(&it);
+    }
+    if let Some(it) = TypeBound::cast(node.clone()) {
+        let _ = it.sub_token();
+        let _ = it.ty();
+    }
+    if let Some(it) = ObjectSort::cast(node.clone()) {
+        let _ = it.object_token();
+        let _ = it.actor_token();
+        let _ = it.module_token();
+    }
+    if let Some(it) = TypeField::cast(node.clone()) {
+        let _ = it.var_token();
+        let _ = ast::HasName::name(&it);
+        let _ = ast::HasTypeAnnotation::type_annotation(&it);
+    }
+    if let Some(it) = TypeFieldFunc::cast(node.clone()) {
+        let _ = it.func_arg();
+        let _ = it.colon_token();
+        let _ = it.func_result();
+        let _ = ast::HasName::name(&it);
+        let _ = ast::HasTypeParams::type_params(&it);
+    }
+    if let Some(it) = TypeAnnotation::cast(node.clone()) {
+        let _ = it.colon_token();
+        let _ = it.ty();
+    }
+    if let Some(it) = TypeTag::cast(node.clone()) {
+        let _ = it.hash_token();
+        let _ = ast::HasName::name(&it);
+        let _ = ast::HasTypeAnnotation::type_annotation(&it);
+    }
+    if let Some(it) = WildcardPat::cast(node.clone()) {
+        let _ = it.underscore_token();
+    }
+    if let Some(it) = VarPat::cast(node.clone()) {
+        let _ = ast::HasName::name(&it);
+    }
+    if let Some(it) = LiteralPat::cast(node.clone()) {
+        let _ = it.literal();
+    }
+    if let Some(it) = ParenPat::cast(node.clone()) {
+        let _ = it.l_paren_token();
+        let _ = it.pattern();
+        let _ = it.r_paren_token();
+    }
+    if let Some(it) = TuplePat::cast(node.clone()) {
+        let _ = it.l_paren_token();
+        let _ = it.patterns().count();
+        let _ = it.r_paren_token();
+    }
+    if let Some(it) = ObjectPat::cast(node.clone()) {
+        let _ = it.l_brace_token();
+        let _ = it.fields().count();
+        let _ = it.r_brace_token();
+    }
+    if let Some(it) = VariantPat::cast(node.clone()) {
+        let _ = it.tag();
+        let _ = it.pattern();
+    }
+    if let Some(it) = OptionPat::cast(node.clone()) {
+        let _ = it.question_mark_token();
+        let _ = it.pattern();
+    }
+    if let Some(it) = AltPat::cast(node.clone()) {
+        let _ = it.or_token();
+    }
+    if let Some(it) = AnnotPat::cast(node.clone()) {
+        let _ = it.pattern();
+        let _ = ast::HasTypeAnnotation::type_annotation(&it);
+    }
+    if let Some(it) = PatternFieldPun::cast(node.clone()) {
+        let _ = ast::HasName::name(&it);
+        let _ = ast::HasTypeAnnotation::type_annotation(&it);
+    }
+    if let Some(it) = PatternFieldPat::cast(node.clone()) {
+        let _ = it.eq_token();
+        let _ = it.pattern();
+        let _ = ast::HasName::name(&it);
+        let _ = ast::HasTypeAnnotation::type_annotation(&it);
+    }
+    if let Some(it) = Tag::cast(node.clone()) {
+        let _ = it.hash_token();
+        let _ = it.ident_token();
+        let _ = it.ident();
+    }
+    if let Some(it) = LiteralExpr::cast(node.clone()) {
+        let _ = it.literal();
+    }
+    if let Some(it) = VarExpr::cast(node.clone()) {
+        let _ = ast::HasName::name(&it);
+    }
+    if let Some(it) = ParenExpr::cast(node.clone()) {
+        let _ = it.l_paren_token();
+        let _ = it.expr();
+        let _ = it.r_paren_token();
+    }
+    if let Some(it) = TupleExpr::cast(node.clone()) {
+        let _ = it.l_paren_token();
+        let _ = it.exprs().count();
+        let _ = it.r_paren_token();
+    }
+    if let Some(it) = PrimExpr::cast(node.clone()) {
+        let _ = it.prim_token();
+        let _ = it.literal();
+    }
+    if let Some(it) = ArrayExpr::cast(node.clone()) {
+        let _ = it.l_brack_token();
+        let _ = it.var_token();
+        let _ = it.elements().count();
+        let _ = it.r_brack_token();
+    }
+    if let Some(it) = IndexExpr::cast(node.clone()) {
+        let _ = it.l_brack_token();
+        let _ = it.r_brack_token();
+    }
+    if let Some(it) = ProjExpr::cast(node.clone()) {
+        let _ = it.expr();
+        let _ = it.dot_num_token();
+    }
+    if let Some(it) = DotExpr::cast(node.clone()) {
+        let _ = it.expr();
+        let _ = it.dot_token();
+        let _ = ast::HasName::name(&it);
+    }
+    if let Some(it) = CallExpr::cast(node.clone()) {
+        let _ = it.callee();
+        let _ = it.type_args();
+    }
+    if let Some(it) = VariantExpr::cast(node.clone()) {
+        let _ = it.tag();
+        let _ = it.expr();
+    }
+    if let Some(it) = OptionExpr::cast(node.clone()) {
+        let _ = it.question_mark_token();
+        let _ = it.expr();
+    }
+    if let Some(it) = UnaryExpr::cast(node.clone()) {
+        let _ = it.expr();
+    }
+    if let Some(it) = ActorUrlExpr::cast(node.clone()) {
+        let _ = it.actor_token();
+        let _ = it.expr();
+    }
+    if let Some(it) = ShowExpr::cast(node.clone()) {
+        let _ = it.debug_show_token();
+        let _ = it.expr();
+    }
+    if let Some(it) = AnnotExpr::cast(node.clone()) {
+        let _ = it.expr();
+        let _ = ast::HasTypeAnnotation::type_annotation(&it);
+    }
+    if let Some(it) = ReturnExpr::cast(node.clone()) {
+        let _ = it.return_token();
+        let _ = it.expr();
+    }
+    if let Some(it) = AsyncExpr::cast(node.clone()) {
+        let _ = it.async_token();
+        let _ = it.async_star_token();
+        let _ = it.expr();
+    }
+    if let Some(it) = AwaitExpr::cast(node.clone()) {
+        let _ = it.await_token();
+        let _ = it.await_star_token();
+        let _ = it.expr();
+    }
+    if let Some(it) = AssertExpr::cast(node.clone()) {
+        let _ = it.assert_token();
+        let _ = it.expr();
+    }
+    if let Some(it) = DebugExpr::cast(node.clone()) {
+        let _ = it.debug_token();
+        let _ = it.expr();
+    }
+    if let Some(it) = IfExpr::cast(node.clone()) {
+        let _ = it.if_token();
+        let _ = it.else_token();
+    }
+    if let Some(it) = SwitchExpr::cast(node.clone()) {
+        let _ = it.switch_token();
+        let _ = it.expr();
+        let _ = it.l_brace_token();
+        let _ = it.arms().count();
+        let _ = it.r_brace_token();
+    }
+    if let Some(it) = TryExpr::cast(node.clone()) {
+        let _ = it.try_token();
+        let _ = it.expr();
+        let _ = it.catch_clause();
+    }
+    if let Some(it) = ThrowExpr::cast(node.clone()) {
+        let _ = it.throw_token();
+        let _ = it.expr();
+    }
+    if let Some(it) = WhileExpr::cast(node.clone()) {
+        let _ = it.while_token();
+    }
+    if let Some(it) = LoopExpr::cast(node.clone()) {
+        let _ = it.loop_token();
+        let _ = it.while_token();
+    }
+    if let Some(it) = ForExpr::cast(node.clone()) {
+        let _ = it.for_token();
+        let _ = it.l_paren_token();
+        let _ = it.pattern();
+        let _ = it.in_token();
+        let _ = it.iterable();
+        let _ = it.r_paren_token();
+    }
+    if let Some(it) = LabelExpr::cast(node.clone()) {
+        let _ = it.label_token();
+        let _ = ast::HasName::name(&it);
+        let _ = ast::HasTypeAnnotation::type_annotation(&it);
+    }
+    if let Some(it) = BreakExpr::cast(node.clone()) {
+        let _ = it.break_token();
+        let _ = it.expr();
+        let _ = ast::HasName::name(&it);
+    }
+    if let Some(it) = ContinueExpr::cast(node.clone()) {
+        let _ = it.continue_token();
+        let _ = ast::HasName::name(&it);
+    }
+    if let Some(it) = IgnoreExpr::cast(node.clone()) {
+        let _ = it.ignore_token();
+        let _ = it.expr();
+    }
+    if let Some(it) = BlockExpr::cast(node.clone()) {
+        let _ = it.l_brace_token();
+        let _ = it.decls().count();
+        let _ = it.r_brace_token();
+    }
+    if let Some(it) = DoExpr::cast(node.clone()) {
+        let _ = it.do_token();
+        let _ = it.block_expr();
+    }
+    if let Some(it) = ObjectExpr::cast(node.clone()) {
+        let _ = it.l_brace_token();
+        let _ = it.bases().count();
+        let _ = it.with_token();
+        let _ = it.fields().count();
+        let _ = it.r_brace_token();
+    }
+    if let Some(it) = LetDecl::cast(node.clone()) {
+        let _ = it.let_token();
+        let _ = it.pattern();
+        let _ = it.eq_token();
+        let _ = it.expr();
+        let _ = ast::HasDocComments::doc_comments(&it);
+    }
+    if let Some(it) = VarDecl::cast(node.clone()) {
+        let _ = it.var_token();
+        let _ = it.eq_token();
+        let _ = it.expr();
+        let _ = ast::HasName::name(&it);
+        let _ = ast::HasTypeAnnotation::type_annotation(&it);
+        let _ = ast::HasDocComments::doc_comments(&it);
+    }
+    if let Some(it) = TypeDecl::cast(node.clone()) {
+        let _ = it.type_token();
+        let _ = it.eq_token();
+        let _ = it.ty();
+        let _ = ast::HasName::name(&it);
+        let _ = ast::HasTypeParams::type_params(&it);
+        let _ = ast::HasDocComments::doc_comments(&it);
+    }
+    if let Some(it) = FuncDecl::cast(node.clone()) {
+        let _ = it.func_sort();
+        let _ = it.func_token();
+        let _ = it.param();
+        let _ = it.eq_token();
+        let _ = ast::HasName::name(&it);
+        let _ = ast::HasTypeParams::type_params(&it);
+        let _ = ast::HasTypeAnnotation::type_annotation(&it);
+        let _ = ast::HasDocComments::doc_comments(&it);
+    }
+    if let Some(it) = ClassDecl::cast(node.clone()) {
+        let _ = it.func_sort();
+        let _ = it.object_sort();
+        let _ = it.class_token();
+        let _ = it.param();
+        let _ = it.class_body();
+        let _ = ast::HasName::name(&it);
+        let _ = ast::HasTypeParams::type_params(&it);
+        let _ = ast::HasTypeAnnotation::type_annotation(&it);
+        let _ = ast::HasDocComments::doc_comments(&it);
+    }
+    if let Some(it) = ObjectDecl::cast(node.clone()) {
+        let _ = it.object_sort();
+        let _ = it.eq_token();
+        let _ = it.object_body();
+        let _ = ast::HasName::name(&it);
+        let _ = ast::HasDocComments::doc_comments(&it);
+    }
+    if let Some(it) = CaseArm::cast(node.clone()) {
+        let _ = it.case_token();
+        let _ = it.pattern();
+    }
+    if let Some(it) = CatchClause::cast(node.clone()) {
+        let _ = it.catch_token();
+        let _ = it.pattern();
+    }
+    if let Some(it) = ExpField::cast(node.clone()) {
+        let _ = it.var_token();
+        let _ = it.eq_token();
+        let _ = it.expr();
+        let _ = ast::HasName::name(&it);
+        let _ = ast::HasTypeAnnotation::type_annotation(&it);
+    }
+    if let Some(it) = ExpDecl::cast(node.clone()) {
+        let _ = it.expr();
+        let _ = ast::HasDocComments::doc_comments(&it);
+    }
+    if let Some(it) = ClassBody::cast(node.clone()) {
+        let _ = it.eq_token();
+        let _ = it.object_body();
+        let _ = ast::HasName::name(&it);
+    }
+    if let Some(it) = ObjectBody::cast(node.clone()) {
+        let _ = it.l_brace_token();
+        let _ = it.fields().count();
+        let _ = it.r_brace_token();
+    }
+    if let Some(it) = DeclField::cast(node.clone()) {
+        let _ = it.stability();
+        let _ = it.decl();
+        let _ = ast::HasVisibility::visibility(&it);
+        let _ = ast::HasDocComments::doc_comments(&it);
+    }
+    if let Some(it) = Visibility::cast(node.clone()) {
+        let _ = it.public_token();
+        let _ = it.private_token();
+        let _ = it.system_token();
+    }
+    if let Some(it) = Stability::cast(node.clone()) {
+        let _ = it.stable_token();
+        let _ = it.flexible_token();
+    }
+    if let Some(it) = Decl::cast(node.clone()) {
+        let _ = it.syntax();
+        let _ = ast::HasDocComments::doc_comments(&it);
+    }
+    if let Some(it) = Pattern::cast(node.clone()) {
+        let _ = it.syntax();
+    }
+    if let Some(it) = Type::cast(node.clone()) {
+        let _ = it.syntax();
+    }
+    if let Some(it) = ObjectField::cast(node.clone()) {
+        let _ = it.syntax();
+        let _ = ast::HasName::name(&it);
+    }
+    if let Some(it) = PatternField::cast(node.clone()) {
+        let _ = it.syntax();
+        let _ = ast::HasName::name(&it);
+        let _ = ast::HasTypeAnnotation::type_annotation(&it);
+    }
+    if let Some(it) = Expr::cast(node.clone()) {
+        let _ = it.syntax();
+    }
+}
@@ -0,0 +1,179 @@
+//! Checks that the trees the parser builds for the passing fixtures have the
+//! shape `grammar.ungram` describes, because that's what the generated
+//! accessors in `nodes.rs` rely on.
+use mokoto::parser::{Parse, Parser};
+use mokoto::syntax::{SyntaxElement, SyntaxNode};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use ungrammar::{Grammar, Node, Rule};
+
+type EntryPoint = fn(&str) -> Parse;
+
+fn to_upper_snake_case(s: &str) -> String {
+    let mut buf = String::with_capacity(s.len());
+    let mut prev = false;
+    for c in s.chars() {
+        if c.is_ascii_uppercase() && prev {
+            buf.push('_')
+        }
+        prev = true;
+
+        buf.push(c.to_ascii_uppercase());
+    }
+    buf
+}
+
+fn kind_name(element: &SyntaxElement) -> String {
+    format!("{:?}", element.kind())
+}
+
+/// Enums like `Expr` are alternatives of nodes and have no node of their own
+fn enum_variants(grammar: &Grammar, node: Node) -> Option<Vec<Node>> {
+    match &grammar[node].rule {
+        Rule::Alt(alts) => alts
+            .iter()
+            .map(|alt| match alt {
+                Rule::Node(node) => Some(*node),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+fn node_matches(grammar: &Grammar, node: Node, element: &SyntaxElement) -> bool {
+    match enum_variants(grammar, node) {
+        Some(variants) => variants
+            .into_iter()
+            .any(|variant| node_matches(grammar, variant, element)),
+        None => {
+            element.as_node().is_some()
+                && kind_name(element) == to_upper_snake_case(&grammar[node].name)
+        }
+    }
+}
+
+fn token_matches(name: &str, element: &SyntaxElement) -> bool {
+    match element.as_token() {
+        // Punctuation and keywords are named after their text, other tokens
+        // after their kind
+        Some(token) => token.text() == name || kind_name(element).to_lowercase() == name,
+        None => false,
+    }
+}
+
+/// All positions `rule` can end at when it starts matching `children` at `start`
+fn ends(
+    grammar: &Grammar,
+    rule: &Rule,
+    children: &[SyntaxElement],
+    start: usize,
+) -> BTreeSet<usize> {
+    match rule {
+        Rule::Labeled { rule, .. } => ends(grammar, rule, children, start),
+        Rule::Node(node) => children
+            .get(start)
+            .filter(|child| node_matches(grammar, *node, child))
+            .map(|_| start + 1)
+            .into_iter()
+            .collect(),
+        Rule::Token(token) => children
+            .get(start)
+            .filter(|child| token_matches(&grammar[*token].name, child))
+            .map(|_| start + 1)
+            .into_iter()
+            .collect(),
+        Rule::Seq(rules) => rules
+            .iter()
+            .fold(Some(start).into_iter().collect(), |acc, rule| {
+                acc.into_iter()
+                    .flat_map(|pos| ends(grammar, rule, children, pos))
+                    .collect()
+            }),
+        Rule::Alt(rules) => rules
+            .iter()
+            .flat_map(|rule| ends(grammar, rule, children, start))
+            .collect(),
+        Rule::Opt(rule) => {
+            let mut res = ends(grammar, rule, children, start);
+            res.insert(start);
+            res
+        }
+        Rule::Rep(rule) => {
+            let mut res: BTreeSet<usize> = Some(start).into_iter().collect();
+            let mut todo = vec![start];
+            while let Some(pos) = todo.pop() {
+                for end in ends(grammar, rule, children, pos) {
+                    if res.insert(end) {
+                        todo.push(end)
+                    }
+                }
+            }
+            res
+        }
+    }
+}
+
+/// Returns a description of every node in `tree` whose children don't match
+/// its rule in the grammar
+fn check_tree(grammar: &Grammar, tree: &SyntaxNode) -> Vec<String> {
+    let mut errors = vec![];
+    for node in tree.descendants() {
+        let kind = format!("{:?}", node.kind());
+        let rule = grammar
+            .iter()
+            .find(|n| to_upper_snake_case(&grammar[*n].name) == kind);
+        let rule = match rule {
+            Some(rule) => &grammar[rule].rule,
+            None => {
+                errors.push(format!("{} has no rule in the grammar", kind));
+                continue;
+            }
+        };
+        let children: Vec<_> = node
+            .children_with_tokens()
+            .filter(|child| !child.kind().is_trivia())
+            .collect();
+        if !ends(grammar, rule, &children, 0).contains(&children.len()) {
+            let children: Vec<_> = children.iter().map(kind_name).collect();
+            errors.push(format!(
+                "{} doesn't match the grammar: {}",
+                kind,
+                children.join(" ")
+            ));
+        }
+    }
+    errors
+}
+
+#[test]
+fn parse_trees_match_the_grammar() {
+    let grammar: Grammar = fs::read_to_string("src/syntax/grammar.ungram")
+        .unwrap()
+        .parse()
+        .unwrap();
+    // The fixture directory for each entry point
+    let entry_points: &[(&str, EntryPoint)] = &[
+        ("types", |input| Parser::new(input).parse_typ()),
+        ("patterns", |input| Parser::new(input).parse_pattern()),
+        ("expressions", |input| Parser::new(input).parse_expr()),
+        ("programs", |input| Parser::new(input).parse_prog()),
+    ];
+
+    let mut errors = vec![];
+    for (category, parse) in entry_points {
+        let dir = Path::new("tests/passing").join(category);
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let input = fs::read_to_string(&path).unwrap().replace("\r\n", "\n");
+            for inp in input.split("---\n") {
+                let tree = parse(inp).syntax();
+                for error in check_tree(&grammar, &tree) {
+                    errors.push(format!("{}: {}\n{}", path.display(), error, inp));
+                }
+            }
+        }
+    }
+    assert!(errors.is_empty(), "{}", errors.join("\n"));
+}
@@ -0,0 +1,251 @@
+use mokoto::parser::Parser;
+use mokoto::syntax::ast::AstNode;
+use mokoto::syntax::nodes::{Literal, ProjExpr};
+use mokoto::syntax::number::{BigUint, NumberError, NumberErrorKind};
+use mokoto::syntax::unescape::{EscapeError, EscapeErrorKind};
+use rowan::{TextRange, TextSize};
+
+fn literal(input: &str) -> Literal {
+    let parse = Parser::new(input).parse_expr();
+    parse
+        .syntax()
+        .descendants()
+        .find_map(Literal::cast)
+        .unwrap()
+}
+
+fn error(kind: EscapeErrorKind, start: u32, end: u32) -> EscapeError {
+    EscapeError {
+        kind,
+        range: TextRange::new(TextSize::from(start), TextSize::from(end)),
+    }
+}
+
+#[test]
+fn decode_text() {
+    let cases = [
+        (r#""hello""#, "hello"),
+        (r#""a\nb\tc\rd""#, "a\nb\tc\rd"),
+        (r#""\\ \" \'""#, "\\ \" '"),
+        (r#""\u{1F600}""#, "\u{1F600}"),
+        (r#""\u{1_F600}""#, "\u{1F600}"),
+        (r#""\41\42""#, "AB"),
+        (r#""\c3\a9""#, "é"),
+        ("\"multi\nline\"", "multi\nline"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(literal(input).text_value(), Some(Ok(expected.to_string())));
+    }
+}
+
+#[test]
+fn decode_char() {
+    assert_eq!(literal("'a'").char_value(), Some(Ok('a')));
+    assert_eq!(literal(r"'\n'").char_value(), Some(Ok('\n')));
+    assert_eq!(literal(r"'\u{E9}'").char_value(), Some(Ok('é')));
+    assert_eq!(literal(r"'\c3\a9'").char_value(), Some(Ok('é')));
+    assert_eq!(literal("'a'").text_value(), None);
+    assert_eq!(literal("\"a\"").char_value(), None);
+}
+
+#[test]
+fn malformed_escapes() {
+    use EscapeErrorKind::*;
+    let cases = [
+        (r#""a\qb""#, vec![error(UnknownEscape, 2, 4)]),
+        (r#""\4""#, vec![error(InvalidByteEscape, 1, 3)]),
+        (r#""\u""#, vec![error(MalformedUnicodeEscape, 1, 3)]),
+        (r#""\u{}""#, vec![error(MalformedUnicodeEscape, 1, 4)]),
+        (r#""\u{D800}""#, vec![error(InvalidCodePoint, 1, 9)]),
+        (r#""\u{FFFFFFFFF}""#, vec![error(InvalidCodePoint, 1, 14)]),
+        (r#""ok\ff""#, vec![error(InvalidUtf8, 3, 6)]),
+        (
+            r#""\q \z""#,
+            vec![error(UnknownEscape, 1, 3), error(UnknownEscape, 4, 6)],
+        ),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(
+            literal(input).text_value(),
+            Some(Err(expected)),
+            "{}",
+            input
+        );
+    }
+    assert_eq!(
+        literal("'ab'").char_value(),
+        Some(Err(vec![error(NotOneChar, 0, 4)]))
+    );
+    assert_eq!(
+        literal("''").char_value(),
+        Some(Err(vec![error(NotOneChar, 0, 2)]))
+    );
+}
+
+#[test]
+fn escape_errors_are_offset_into_the_source() {
+    let parse = Parser::new(r#"f(1, "\q")"#).parse_expr();
+    let literal = parse
+        .syntax()
+        .descendants()
+        .filter_map(Literal::cast)
+        .nth(1)
+        .unwrap();
+    assert_eq!(
+        literal.text_value(),
+        Some(Err(vec![error(EscapeErrorKind::UnknownEscape, 6, 8)]))
+    );
+}
+
+#[test]
+fn decode_nat() {
+    let cases = [
+        ("0", 0u64),
+        ("1_000_000", 1_000_000),
+        ("0xFF_FF", 0xFFFF),
+        ("0xdeadBEEF", 0xDEAD_BEEF),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(
+            literal(input).nat_value(),
+            Some(Ok(BigUint::from(expected))),
+            "{}",
+            input
+        );
+    }
+    let big = "340282366920938463463374607431768211456";
+    assert_eq!(
+        literal(big).nat_value(),
+        Some(Ok(big.parse::<BigUint>().unwrap()))
+    );
+    assert_eq!(literal("1.5").nat_value(), None);
+}
+
+#[test]
+fn decode_float() {
+    let rounded = f64::from_bits(0x3FF2_3456_789A_BCDF);
+    let cases = [
+        ("2.75", 2.75),
+        ("1.", 1.0),
+        ("1_0.2_5", 10.25),
+        ("1e10", 1e10),
+        ("2.5E-3", 2.5e-3),
+        ("1.5e+2", 150.0),
+        ("0x1.8p3", 12.0),
+        ("0xA.", 10.0),
+        ("0x1p-2", 0.25),
+        // Long mantissas are rounded once, to nearest and ties to even
+        ("0x1.23456789abcdefp0", rounded),
+        ("0x1.00000000000008p0", 1.0),
+        ("0x1.00000000000008000001p0", 1.0 + f64::EPSILON),
+        ("0x123456789abcdef0123p-72", rounded),
+        // Exponents at the edges of the range
+        ("0x1p-1022", f64::MIN_POSITIVE),
+        ("0x1.fffffffffffffp1023", f64::MAX),
+        ("0x0.0000000000001p-1022", f64::from_bits(1)),
+        ("0x100000p-1090", f64::from_bits(16)),
+        ("0x0.000001p1047", 2f64.powi(1023)),
+        ("0x1p-1075", 0.0),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(
+            literal(input).float_value(),
+            Some(Ok(expected)),
+            "{}",
+            input
+        );
+    }
+    assert_eq!(literal("1").float_value(), None);
+}
+
+#[test]
+fn malformed_numbers() {
+    use NumberErrorKind::*;
+    let error = |kind, start: u32, end: u32| NumberError {
+        kind,
+        range: TextRange::new(TextSize::from(start), TextSize::from(end)),
+    };
+    assert_eq!(
+        literal("1__0").nat_value(),
+        Some(Err(error(MisplacedUnderscore, 1, 2)))
+    );
+    assert_eq!(
+        literal("1_").nat_value(),
+        Some(Err(error(MisplacedUnderscore, 1, 2)))
+    );
+    assert_eq!(
+        literal("0x_1").nat_value(),
+        Some(Err(error(MisplacedUnderscore, 2, 3)))
+    );
+    assert_eq!(
+        literal("0x").nat_value(),
+        Some(Err(error(MissingDigits, 0, 2)))
+    );
+    assert_eq!(
+        literal("1._5").float_value(),
+        Some(Err(error(MisplacedUnderscore, 2, 3)))
+    );
+    assert_eq!(
+        literal("1e999").float_value(),
+        Some(Err(error(FloatOutOfRange, 0, 5)))
+    );
+    assert_eq!(
+        literal("0x1p1024").float_value(),
+        Some(Err(error(FloatOutOfRange, 0, 8)))
+    );
+}
+
+#[test]
+fn tuple_projections() {
+    let parse = Parser::new("t.0.1_0").parse_expr();
+    let indices: Vec<_> = parse
+        .syntax()
+        .descendants()
+        .filter_map(ProjExpr::cast)
+        .map(|proj| proj.index())
+        .collect();
+    assert_eq!(indices, vec![Some(10), Some(0)]);
+}
+
+#[test]
+fn literal_tokens() {
+    assert_eq!(
+        literal("0xFF").nat_lit().unwrap().value(),
+        Ok(BigUint::from(255u32))
+    );
+    assert_eq!(literal("1.5").float_lit().unwrap().value(), Ok(1.5));
+    assert_eq!(
+        literal(r#""a\tb""#).text_lit().unwrap().value(),
+        Ok("a\tb".to_string())
+    );
+    assert_eq!(literal(r"'\n'").char_lit().unwrap().value(), Ok('\n'));
+    assert!(literal("1").text_lit().is_none());
+}
+
+#[test]
+fn trivia_tokens() {
+    use mokoto::syntax::ast::AstToken;
+    use mokoto::syntax::tokens::{Comment, Whitespace};
+
+    let parse = Parser::new("/// doc\nlet x = 1; // note\n\n\nx").parse_prog();
+    let tokens: Vec<_> = parse
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|it| it.into_token())
+        .collect();
+    let comments: Vec<_> = tokens
+        .iter()
+        .cloned()
+        .filter_map(Comment::cast)
+        .map(|c| (c.is_doc(), c.is_block()))
+        .collect();
+    assert_eq!(comments, [(true, false), (false, false)]);
+    let blank_lines: Vec<_> = tokens
+        .iter()
+        .cloned()
+        .filter_map(Whitespace::cast)
+        .filter(|w| w.syntax().kind() == mokoto::lexer::SyntaxKind::LINEFEED)
+        .map(|w| w.spans_multiple_lines())
+        .collect();
+    assert_eq!(blank_lines, [false, true, true, true]);
+}
@@ -0,0 +1,147 @@
+use mokoto::parser::Parser;
+use mokoto::syntax::ast::AstNode;
+use mokoto::syntax::make;
+use mokoto::syntax::nodes::{Decl, Pattern, Type};
+
+/// The tree has the expected text, and is exactly the tree we get when
+/// parsing it
+fn assert_type(ty: impl Into<Type>, expected: &str) {
+    let ty: Type = ty.into();
+    assert_eq!(ty.syntax().to_string(), expected);
+    let parse = Parser::new(expected).parse_typ();
+    assert!(parse.errors().is_empty(), "{:?}", parse.errors());
+    assert_eq!(
+        format!("{:#?}", ty.syntax()),
+        format!("{:#?}", parse.syntax())
+    );
+}
+
+fn assert_decl(decl: impl Into<Decl>, expected: &str) {
+    let decl: Decl = decl.into();
+    assert_eq!(decl.syntax().to_string(), expected);
+    let parse = Parser::new(expected).parse_prog();
+    assert!(parse.errors().is_empty(), "{:?}", parse.errors());
+    let parsed = parse.syntax().first_child().unwrap();
+    assert_eq!(format!("{:#?}", decl.syntax()), format!("{:#?}", parsed));
+}
+
+fn named(name: &str) -> Type {
+    make::path_type([name], []).into()
+}
+
+#[test]
+fn path_types() {
+    assert_type(make::path_type(["Nat"], []), "Nat");
+    assert_type(
+        make::path_type(["Array", "Array"], [named("Nat"), named("Text")]),
+        "Array.Array<Nat, Text>",
+    );
+}
+
+#[test]
+fn prim_types() {
+    assert_type(make::any_type(), "Any");
+    assert_type(make::none_type(), "None");
+    assert_type(make::optional_type(make::null_type().into()), "?Null");
+}
+
+#[test]
+fn func_types() {
+    let unit: Type = make::tuple_type([]).into();
+    assert_type(
+        make::func_type(
+            [named("Nat"), named("Text")],
+            make::async_type(named("Text")).into(),
+        ),
+        "(Nat, Text) -> async Text",
+    );
+    assert_type(make::func_type([named("Nat")], unit.clone()), "(Nat) -> ()");
+    assert_type(
+        make::shared_func_type(true, [], make::async_type(named("Nat")).into()),
+        "shared query () -> async Nat",
+    );
+    assert_type(
+        make::func_type([], make::func_type([], unit).into()),
+        "() -> () -> ()",
+    );
+}
+
+#[test]
+fn object_and_variant_types() {
+    let name = make::name;
+    assert_type(make::object_type([]), "{}");
+    assert_type(
+        make::object_type([
+            make::type_field(false, name("a"), named("Nat")),
+            make::type_field(true, name("b"), named("Text")),
+        ]),
+        "{ a : Nat; var b : Text }",
+    );
+    assert_type(
+        make::actor_type([make::type_field(
+            false,
+            name("get"),
+            make::shared_func_type(false, [], make::async_type(named("Nat")).into()).into(),
+        )]),
+        "actor { get : shared () -> async Nat }",
+    );
+    assert_type(make::variant_type([]), "{#}");
+    assert_type(
+        make::variant_type([
+            make::type_tag(name("ok"), Some(named("Nat"))),
+            make::type_tag(name("none"), None),
+        ]),
+        "{ #ok : Nat; #none }",
+    );
+}
+
+#[test]
+fn types_are_parenthesized_where_needed() {
+    let func: Type = make::func_type([], named("Nat")).into();
+    let async_nat: Type = make::async_type(named("Nat")).into();
+    assert_type(make::optional_type(func.clone()), "?(() -> Nat)");
+    assert_type(make::optional_type(async_nat.clone()), "?(async Nat)");
+    assert_type(
+        make::async_type(make::optional_type(named("Nat")).into()),
+        "async ?Nat",
+    );
+    assert_type(make::array_type(true, func), "[var () -> Nat]");
+    assert_type(
+        make::array_type(false, make::optional_type(async_nat).into()),
+        "[?(async Nat)]",
+    );
+}
+
+#[test]
+fn declarations() {
+    let name = make::name;
+    assert_decl(
+        make::let_decl(
+            make::var_pat(name("x")).into(),
+            make::var_expr(name("y")).into(),
+        ),
+        "let x = y",
+    );
+    let pat = Pattern::cast(Parser::new("(a, b)").parse_pattern().syntax()).unwrap();
+    assert_decl(
+        make::let_decl(pat, make::var_expr(name("pair")).into()),
+        "let (a, b) = pair",
+    );
+    assert_decl(
+        make::type_decl(
+            name("Result"),
+            make::variant_type([
+                make::type_tag(name("ok"), Some(named("Nat"))),
+                make::type_tag(name("err"), Some(named("Text"))),
+            ])
+            .into(),
+        ),
+        "type Result = { #ok : Nat; #err : Text }",
+    );
+}
+
+#[test]
+#[should_panic(expected = "is not an identifier")]
+fn keywords_are_not_names() {
+    make::name("type");
+}
@@ -1,4 +1,8 @@
+use mokoto::diagnostic::{Severity, SYNTAX_ERROR};
 use mokoto::parser::Parser;
+use mokoto::syntax::ast::{AstNode, HasDocComments, HasName, HasTypeParams, HasVisibility};
+use mokoto::syntax::nodes::{Decl, Expr, SourceFile};
+use rowan::{TextRange, TextSize};
 
 use insta::{assert_snapshot, glob};
 
@@ -16,7 +20,7 @@ fn normalize_newlines(src: &mut String) {
     // directly, let's rather steal the contents of `src`. This makes the code
     // safe even if a panic occurs.
 
-    let mut buf = std::mem::replace(src, String::new()).into_bytes();
+    let mut buf = std::mem::take(src).into_bytes();
     let mut gap_len = 0;
     let mut tail = buf.as_mut_slice();
     loop {
@@ -68,7 +72,7 @@ fn parse_types() {
             input
         };
         for inp in input.split("---\n") {
-            let parse = Parser::new(&inp).parse_typ();
+            let parse = Parser::new(inp).parse_typ();
             let output = format!("{}\n---\n{}", inp, parse.debug_tree());
             assert_snapshot!(output);
         }
@@ -86,9 +90,383 @@ fn parse_patterns() {
             input
         };
         for inp in input.split("---\n") {
-            let parse = Parser::new(&inp).parse_pattern();
+            let parse = Parser::new(inp).parse_pattern();
             let output = format!("{}\n---\n{}", inp, parse.debug_tree());
             assert_snapshot!(output);
         }
     });
 }
+
+#[test]
+fn parse_expressions() {
+    use std::fs;
+
+    glob!("passing/expressions/*.mo", |path| {
+        let input = {
+            let mut input = fs::read_to_string(path).unwrap();
+            normalize_newlines(&mut input);
+            input
+        };
+        for inp in input.split("---\n") {
+            let parse = Parser::new(inp).parse_expr();
+            let output = format!("{}\n---\n{}", inp, parse.debug_tree());
+            assert_snapshot!(output);
+        }
+    });
+}
+
+#[test]
+fn diagnostics_point_at_the_error() {
+    let parse = Parser::new("a < b < c").parse_expr();
+    let errors = parse.errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].severity, Severity::Error);
+    assert_eq!(errors[0].code, Some(SYNTAX_ERROR));
+    assert_eq!(
+        errors[0].range,
+        TextRange::new(TextSize::from(6), TextSize::from(7))
+    );
+
+    let parse = Parser::new("f(1,\n  2").parse_expr();
+    let errors = parse.errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "Expected a )");
+    assert_eq!(errors[0].range, TextRange::empty(TextSize::from(8)));
+    assert_eq!(
+        errors[0].labels[0].range,
+        TextRange::new(TextSize::from(1), TextSize::from(2))
+    );
+}
+
+#[test]
+fn recover_expressions() {
+    use std::fs;
+
+    glob!("failing/expressions/*.mo", |path| {
+        let input = {
+            let mut input = fs::read_to_string(path).unwrap();
+            normalize_newlines(&mut input);
+            input
+        };
+        for inp in input.split("---\n") {
+            let parse = Parser::new(inp).parse_expr();
+            let output = format!("{}\n---\n{}", inp, parse.debug_tree());
+            assert_snapshot!(output);
+        }
+    });
+}
+
+#[test]
+fn parse_programs() {
+    use std::fs;
+
+    glob!("passing/programs/*.mo", |path| {
+        let input = {
+            let mut input = fs::read_to_string(path).unwrap();
+            normalize_newlines(&mut input);
+            input
+        };
+        for inp in input.split("---\n") {
+            let parse = Parser::new(inp).parse_prog();
+            let output = format!("{}\n---\n{}", inp, parse.debug_tree());
+            assert_snapshot!(output);
+        }
+    });
+}
+
+#[test]
+fn recover_programs() {
+    use std::fs;
+
+    glob!("failing/programs/*.mo", |path| {
+        let input = {
+            let mut input = fs::read_to_string(path).unwrap();
+            normalize_newlines(&mut input);
+            input
+        };
+        for inp in input.split("---\n") {
+            let parse = Parser::new(inp).parse_prog();
+            let output = format!("{}\n---\n{}", inp, parse.debug_tree());
+            assert_snapshot!(output);
+        }
+    });
+}
+
+#[test]
+fn source_file_accessors() {
+    let parse = Parser::new("import A \"a\"; import B \"b\"; let x = 1; x").parse_prog();
+    let file = SourceFile::cast(parse.syntax()).unwrap();
+    assert_eq!(file.imports().count(), 2);
+    let decls: Vec<_> = file.decls().collect();
+    assert!(matches!(decls[..], [Decl::LetDecl(_), Decl::ExpDecl(_)]));
+}
+
+#[test]
+fn declaration_accessors() {
+    let parse = Parser::new("func f<T>(x : T) : T = x; actor class C() { }").parse_prog();
+    let file = SourceFile::cast(parse.syntax()).unwrap();
+    let decls: Vec<_> = file.decls().collect();
+    let func = match &decls[0] {
+        Decl::FuncDecl(func) => func,
+        _ => panic!("Expected a function"),
+    };
+    assert_eq!(func.name().unwrap().syntax().text(), "f");
+    assert_eq!(func.type_params().unwrap().params().count(), 1);
+    assert_eq!(func.param().unwrap().idents().len(), 1);
+    assert!(matches!(func.body(), Some(Expr::VarExpr(_))));
+    let class = match &decls[1] {
+        Decl::ClassDecl(class) => class,
+        _ => panic!("Expected a class"),
+    };
+    assert!(class.object_sort().unwrap().actor_token().is_some());
+    assert!(class.class_body().unwrap().object_body().is_some());
+}
+
+#[test]
+fn module_header() {
+    let input = "import A \"mo:base/Array\";\nimport { foo; bar } \"./lib\";\nlet x = 1";
+    let parse = Parser::new(input).parse_module_header();
+    assert!(parse.errors().is_empty());
+    let file = SourceFile::cast(parse.syntax()).unwrap();
+    assert_eq!(file.decls().count(), 0);
+    let imports: Vec<_> = file.imports().collect();
+    assert_eq!(imports[0].path().unwrap().unwrap(), "mo:base/Array");
+    assert_eq!(imports[1].path().unwrap().unwrap(), "./lib");
+    let idents: Vec<_> = imports[1]
+        .pattern()
+        .unwrap()
+        .idents()
+        .iter()
+        .map(|name| name.ident_token().unwrap().text().to_string())
+        .collect();
+    assert_eq!(idents, ["foo", "bar"]);
+}
+
+#[test]
+fn decl_field_modifiers() {
+    let input = "actor { stable var n = 0; public func get() { n }; system func preupgrade() {}; let x = 1 }";
+    let parse = Parser::new(input).parse_prog();
+    let file = SourceFile::cast(parse.syntax()).unwrap();
+    let actor = match file.decls().next() {
+        Some(Decl::ObjectDecl(actor)) => actor,
+        _ => panic!("Expected an actor"),
+    };
+    let fields: Vec<_> = actor.object_body().unwrap().fields().collect();
+    let modifiers: Vec<_> = fields
+        .iter()
+        .map(|f| (f.is_public(), f.is_system(), f.is_stable()))
+        .collect();
+    assert_eq!(
+        modifiers,
+        [
+            (false, false, true),
+            (true, false, false),
+            (false, true, false),
+            (false, false, false)
+        ]
+    );
+}
+
+#[test]
+fn alternative_patterns_bind_the_same_names() {
+    use mokoto::syntax::nodes::Pattern;
+
+    let pattern = |input| {
+        let parse = Parser::new(input).parse_pattern();
+        match Pattern::cast(parse.syntax()).unwrap() {
+            Pattern::AltPat(pat) => pat,
+            _ => panic!("Expected an or pattern"),
+        }
+    };
+    let names = |names: Vec<_>| -> Vec<String> {
+        names
+            .iter()
+            .map(|name: &mokoto::syntax::nodes::Name| {
+                name.ident_token().unwrap().text().to_string()
+            })
+            .collect()
+    };
+
+    let pat = pattern("(#a x or #b x) or #c x");
+    assert!(pat.mismatched_idents().is_empty());
+    assert_eq!(names(Pattern::AltPat(pat).idents()), ["x"]);
+
+    let pat = pattern("#a (x, y) or #b x");
+    assert_eq!(names(pat.mismatched_idents()), ["y"]);
+    assert_eq!(names(Pattern::AltPat(pat).idents()), ["x", "y"]);
+}
+
+#[test]
+fn break_targets() {
+    use mokoto::syntax::nodes::{BreakExpr, ContinueExpr};
+
+    let input = "label a { label b while c { break a; continue b; continue a; \
+                 func f() { break a }; async { break a } } }";
+    let parse = Parser::new(input).parse_expr();
+    let label_name = |label: mokoto::syntax::nodes::LabelExpr| {
+        label
+            .name()
+            .unwrap()
+            .ident_token()
+            .unwrap()
+            .text()
+            .to_string()
+    };
+    let breaks: Vec<_> = parse
+        .syntax()
+        .descendants()
+        .filter_map(BreakExpr::cast)
+        .map(|b| b.target().map(label_name))
+        .collect();
+    assert_eq!(breaks, [Some("a".to_string()), None, None]);
+    // `a` labels a block, not a loop
+    let continues: Vec<_> = parse
+        .syntax()
+        .descendants()
+        .filter_map(ContinueExpr::cast)
+        .map(|c| c.target().map(label_name))
+        .collect();
+    assert_eq!(continues, [Some("b".to_string()), None]);
+}
+
+#[test]
+fn func_sorts() {
+    let input =
+        "func a() {}; shared func b() {}; shared query func c() {}; shared({ caller }) func d() {}";
+    let parse = Parser::new(input).parse_prog();
+    let file = SourceFile::cast(parse.syntax()).unwrap();
+    let sorts: Vec<_> = file
+        .decls()
+        .map(|decl| match decl {
+            Decl::FuncDecl(func) => func.func_sort(),
+            _ => panic!("Expected a function"),
+        })
+        .map(|sort| sort.map(|s| (s.is_shared(), s.is_query(), s.caller().is_some())))
+        .collect();
+    assert_eq!(
+        sorts,
+        [
+            None,
+            Some((true, false, false)),
+            Some((true, true, false)),
+            Some((true, false, true))
+        ]
+    );
+}
+
+#[test]
+fn object_expr_accessors() {
+    use mokoto::syntax::nodes::ObjectExpr;
+
+    let parse = Parser::new("{ a and b with var x = 1; y = 2 }").parse_expr();
+    assert!(parse.errors().is_empty());
+    let object = ObjectExpr::cast(parse.syntax()).unwrap();
+    assert_eq!(object.bases().count(), 2);
+    let fields: Vec<_> = object
+        .fields()
+        .map(|f| (f.var_token().is_some(), f.expr().is_some()))
+        .collect();
+    assert_eq!(fields, [(true, true), (false, true)]);
+
+    // Fields can't be punned
+    let parse = Parser::new("{ a with y }").parse_expr();
+    assert_eq!(parse.errors().len(), 1);
+    let object = ObjectExpr::cast(parse.syntax()).unwrap();
+    assert!(object.fields().next().unwrap().expr().is_none());
+}
+
+#[test]
+fn deprecated_braces_are_warnings() {
+    // Statements are in object mode, like the right-hand side of a `let`
+    let parse = Parser::new("let x = { f(); 1 }; { g(); 2 }; { a = 1 }").parse_prog();
+    let severities: Vec<_> = parse.errors().iter().map(|e| e.severity).collect();
+    assert_eq!(severities, [Severity::Warning, Severity::Warning]);
+    assert!(parse
+        .errors()
+        .iter()
+        .all(|e| e.message.starts_with("block syntax is deprecated")));
+    // moc doesn't give deprecation warnings a code
+    assert!(parse.errors().iter().all(|e| e.code.is_none()));
+}
+
+#[test]
+fn type_operators() {
+    use mokoto::syntax::nodes::{OrType, Type};
+
+    let parse = Parser::new("A and B or C").parse_typ();
+    assert!(parse.errors().is_empty());
+    let or = OrType::cast(parse.syntax()).unwrap();
+    assert!(matches!(or.lhs(), Some(Type::AndType(_))));
+    assert!(matches!(or.rhs(), Some(Type::PathType(_))));
+
+    // `and` in an annotation is the boolean operator
+    let parse = Parser::new("x : Bool and y").parse_expr();
+    assert!(parse.errors().is_empty());
+    assert!(matches!(Expr::cast(parse.syntax()), Some(Expr::BinExpr(_))));
+}
+
+#[test]
+fn declaration_traits() {
+    use mokoto::syntax::nodes::DeclField;
+
+    fn name<N: HasName>(node: &N) -> String {
+        node.name().unwrap().ident().unwrap().to_string()
+    }
+
+    let input = "/// The actor\nactor A {\n  // Not a doc\n  /** Adds */\n  public func add<T>() {};\n  /// Private\n  type T = Nat;\n}";
+    let parse = Parser::new(input).parse_prog();
+    assert!(parse.errors().is_empty());
+    let file = SourceFile::cast(parse.syntax()).unwrap();
+    let decl = file.decls().next().unwrap();
+    let docs: Vec<_> = decl.doc_comments().iter().map(|c| c.to_string()).collect();
+    assert_eq!(docs, ["/// The actor"]);
+    let actor = match decl {
+        Decl::ObjectDecl(actor) => actor,
+        _ => panic!("Expected an actor"),
+    };
+    assert_eq!(name(&actor), "A");
+
+    let fields: Vec<DeclField> = actor.object_body().unwrap().fields().collect();
+    let outline: Vec<_> = fields
+        .iter()
+        .map(|field| {
+            let docs: Vec<_> = field.doc_comments().iter().map(|c| c.to_string()).collect();
+            let name = match field.decl().unwrap() {
+                Decl::FuncDecl(func) => {
+                    assert!(func.type_params().is_some());
+                    name(&func)
+                }
+                Decl::TypeDecl(ty) => name(&ty),
+                _ => panic!("Expected a function or type"),
+            };
+            (field.visibility().is_some(), name, docs)
+        })
+        .collect();
+    assert_eq!(
+        outline,
+        [
+            (true, "add".to_string(), vec!["/** Adds */".to_string()]),
+            (false, "T".to_string(), vec!["/// Private".to_string()]),
+        ]
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn parse_is_parse_prog() {
+    let input = "import A \"a\"; let x = 1";
+    assert_eq!(
+        Parser::new(input).parse().debug_tree(),
+        Parser::new(input).parse_prog().debug_tree()
+    );
+}
+
+#[test]
+fn throw_takes_a_block() {
+    use mokoto::syntax::nodes::ThrowExpr;
+
+    let parse = Parser::new("throw { f(); e }").parse_expr();
+    assert!(parse.errors().is_empty(), "{:?}", parse.errors());
+    let throw = ThrowExpr::cast(parse.syntax()).unwrap();
+    assert!(matches!(throw.expr(), Some(Expr::BlockExpr(_))));
+}
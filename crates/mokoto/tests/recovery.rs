@@ -0,0 +1,326 @@
+use mokoto::parser::{Parse, Parser};
+use mokoto::syntax::ast::{AstNode, AstToken};
+use mokoto::syntax::{nodes, tokens, SyntaxNode};
+use std::fs;
+use std::path::Path;
+
+mod accessors;
+
+type EntryPoint = fn(&str) -> Parse;
+
+const ENTRY_POINTS: &[(&str, EntryPoint)] = &[
+    ("parse_prog", |input| Parser::new(input).parse_prog()),
+    ("parse_typ", |input| Parser::new(input).parse_typ()),
+    ("parse_pattern", |input| Parser::new(input).parse_pattern()),
+    ("parse_expr", |input| Parser::new(input).parse_expr()),
+];
+
+fn fixtures(dir: &Path, inputs: &mut Vec<String>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            fixtures(&path, inputs)
+        } else if path.extension().is_some_and(|ext| ext == "mo") {
+            let input = fs::read_to_string(&path).unwrap().replace("\r\n", "\n");
+            inputs.extend(input.split("---\n").map(|s| s.to_string()))
+        }
+    }
+}
+
+fn assert_lossless(input: &str) {
+    for (name, entry_point) in ENTRY_POINTS {
+        let parse = entry_point(input);
+        assert_eq!(
+            parse.syntax().to_string(),
+            input,
+            "{} lost input for {:?}",
+            name,
+            input
+        );
+    }
+}
+
+/// Calls every accessor on every node, none of them may panic on a tree with
+/// errors
+fn call_accessors(input: &str) {
+    for (_, entry_point) in ENTRY_POINTS {
+        let parse = entry_point(input);
+        for node in parse.syntax().descendants() {
+            accessors::call_all(&node);
+            call_helpers(&node);
+        }
+        for token in parse
+            .syntax()
+            .descendants_with_tokens()
+            .filter_map(|it| it.into_token())
+        {
+            let _ = tokens::Ident::cast(token.clone()).map(|t| t.text_without_escape().len());
+            let _ = tokens::NatLit::cast(token.clone()).map(|t| t.value());
+            let _ = tokens::FloatLit::cast(token.clone()).map(|t| t.value());
+            let _ = tokens::TextLit::cast(token.clone()).map(|t| t.value());
+            let _ = tokens::CharLit::cast(token.clone()).map(|t| t.value());
+            let _ = tokens::Comment::cast(token.clone()).map(|t| (t.is_doc(), t.is_block()));
+            let _ = tokens::Whitespace::cast(token).map(|t| t.spans_multiple_lines());
+        }
+    }
+}
+
+/// The hand written accessors in `syntax.rs`
+fn call_helpers(node: &SyntaxNode) {
+    fn cast<N: AstNode>(node: &SyntaxNode) -> Option<N> {
+        N::cast(node.clone())
+    }
+
+    if let Some(it) = cast::<nodes::Path>(node) {
+        let _ = it.segments();
+    }
+    if let Some(it) = cast::<nodes::Literal>(node) {
+        let _ = (it.token(), it.nat_value(), it.float_value());
+        let _ = (it.text_value(), it.char_value());
+    }
+    if let Some(it) = cast::<nodes::ImportDecl>(node) {
+        let _ = it.path();
+    }
+    if let Some(it) = cast::<nodes::DeclField>(node) {
+        let _ = (it.is_public(), it.is_system(), it.is_stable());
+    }
+    if let Some(it) = cast::<nodes::Pattern>(node) {
+        let _ = it.idents();
+    }
+    if let Some(it) = cast::<nodes::LiteralPat>(node) {
+        let _ = it.op_token();
+    }
+    if let Some(it) = cast::<nodes::AltPat>(node) {
+        let _ = (it.lhs(), it.rhs(), it.mismatched_idents());
+    }
+    if let Some(it) = cast::<nodes::IndexExpr>(node) {
+        let _ = (it.base(), it.index());
+    }
+    if let Some(it) = cast::<nodes::ProjExpr>(node) {
+        let _ = it.index();
+    }
+    if let Some(it) = cast::<nodes::CallExpr>(node) {
+        let _ = it.arg();
+    }
+    if let Some(it) = cast::<nodes::UnaryExpr>(node) {
+        let _ = it.op_token();
+    }
+    if let Some(it) = cast::<nodes::AndType>(node) {
+        let _ = (it.lhs(), it.rhs());
+    }
+    if let Some(it) = cast::<nodes::OrType>(node) {
+        let _ = (it.lhs(), it.rhs());
+    }
+    if let Some(it) = cast::<nodes::BinExpr>(node) {
+        let _ = (it.lhs(), it.op_token(), it.rhs());
+    }
+    if let Some(it) = cast::<nodes::AssignExpr>(node) {
+        let _ = (it.lhs(), it.op_token(), it.rhs());
+    }
+    if let Some(it) = cast::<nodes::IfExpr>(node) {
+        let _ = (it.condition(), it.then_branch(), it.else_branch());
+    }
+    if let Some(it) = cast::<nodes::CaseArm>(node) {
+        let _ = it.body();
+    }
+    if let Some(it) = cast::<nodes::LabelExpr>(node) {
+        let _ = it.body();
+    }
+    if let Some(it) = cast::<nodes::BreakExpr>(node) {
+        let _ = it.target();
+    }
+    if let Some(it) = cast::<nodes::ContinueExpr>(node) {
+        let _ = it.target();
+    }
+    if let Some(it) = cast::<nodes::CatchClause>(node) {
+        let _ = it.body();
+    }
+    if let Some(it) = cast::<nodes::FuncSort>(node) {
+        let _ = (it.is_shared(), it.is_query());
+    }
+    if let Some(it) = cast::<nodes::FuncDecl>(node) {
+        let _ = it.body();
+    }
+    if let Some(it) = cast::<nodes::WhileExpr>(node) {
+        let _ = (it.condition(), it.body());
+    }
+    if let Some(it) = cast::<nodes::LoopExpr>(node) {
+        let _ = (it.body(), it.condition());
+    }
+    if let Some(it) = cast::<nodes::ForExpr>(node) {
+        let _ = it.body();
+    }
+}
+
+/// Every prefix of every valid input is a good source of broken inputs
+#[test]
+fn prefixes_of_fixtures_parse_losslessly() {
+    let mut inputs = vec![];
+    fixtures(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/passing"),
+        &mut inputs,
+    );
+    for input in inputs {
+        for (i, _) in input.char_indices() {
+            assert_lossless(&input[..i]);
+            assert_lossless(&input[i..]);
+            call_accessors(&input[..i]);
+        }
+        assert_lossless(&input);
+    }
+}
+
+#[test]
+fn garbage_parses_losslessly() {
+    let inputs = [
+        "",
+        "   ",
+        "// just a comment\n",
+        ")",
+        "}}}",
+        "{{{",
+        "((((",
+        "let",
+        "let = ;",
+        "var x : = ;",
+        "#",
+        "?",
+        "f<",
+        "a.",
+        "x :",
+        "switch",
+        "switch x { case",
+        "for (",
+        "if",
+        "1 + * 2",
+        "é ü 日本",
+        "\"unterminated",
+        "'",
+        "/* unterminated",
+        "{ x ) y; z }",
+        "[1, 2",
+        "<<>= >>= +>>",
+        "prim",
+        "actor",
+        "shared query <A> -> ()",
+        "{ var : }",
+        "{ #a : ; }",
+        "object",
+    ];
+    for input in inputs {
+        assert_lossless(input);
+        call_accessors(input);
+    }
+}
+
+/// A small xorshift generator, so the random inputs are the same on every run
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+/// Snippets that random inputs are glued together from
+const PIECES: &[&str] = &[
+    " ",
+    "\n",
+    "x",
+    "f",
+    "Nat",
+    "1",
+    "0x_",
+    "1.5e",
+    "\"t\"",
+    "\"\\q\"",
+    "'c'",
+    "'\\u{}'",
+    "_",
+    "#",
+    "?",
+    "!",
+    "=",
+    ":",
+    ";",
+    ",",
+    ".",
+    ".1",
+    "->",
+    "<",
+    ">",
+    "(",
+    ")",
+    "{",
+    "}",
+    "[",
+    "]",
+    "+",
+    "-",
+    "*",
+    "|",
+    "&",
+    "and",
+    "or",
+    "not",
+    "let",
+    "var",
+    "type",
+    "func",
+    "class",
+    "actor",
+    "object",
+    "module",
+    "public",
+    "private",
+    "system",
+    "stable",
+    "flexible",
+    "shared",
+    "query",
+    "async",
+    "async*",
+    "await",
+    "if",
+    "else",
+    "switch",
+    "case",
+    "label",
+    "break",
+    "continue",
+    "return",
+    "loop",
+    "while",
+    "for",
+    "in",
+    "try",
+    "catch",
+    "throw",
+    "do",
+    "with",
+    "import",
+    "prim",
+    "null",
+    "true",
+    "/// doc\n",
+    "/* c */",
+    "// c\n",
+];
+
+#[test]
+fn random_inputs_dont_panic() {
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..2000 {
+        let len = rng.below(40);
+        let input: String = (0..len).map(|_| PIECES[rng.below(PIECES.len())]).collect();
+        assert_lossless(&input);
+        call_accessors(&input);
+    }
+}
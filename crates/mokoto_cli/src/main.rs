@@ -1,6 +1,6 @@
 use mokoto::parser::{Parse, Parser};
 use mokoto::syntax::nodes::Pattern;
-use mokoto::syntax::{ast::AstNode, nodes::Path, nodes::Type};
+use mokoto::syntax::{ast::AstNode, nodes::Type};
 use std::io::{self, Write};
 
 fn main() -> io::Result<()> {
@@ -16,38 +16,44 @@ fn main() -> io::Result<()> {
         stdin.read_line(&mut input)?;
 
         if let Some(input) = input.strip_prefix(":t ") {
-            let parse: Parse = Parser::new(&input).parse_typ();
+            let parse: Parse = Parser::new(input).parse_typ();
             println!("{}", parse.debug_tree());
 
-            let ty: Type = AstNode::cast(parse.syntax()).unwrap();
-            match ty {
-                Type::PathType(path) => {
+            match Type::cast(parse.syntax()) {
+                Some(Type::PathType(path)) => {
                     println!(
                         "{:?}<{:?}>",
-                        path.path().unwrap().segments(),
+                        path.path().map_or(vec![], |p| p.segments()),
                         path.type_args()
                     );
                 }
-                Type::FuncType(func) => {
+                Some(Type::FuncType(func)) => {
                     println!(
                         "{:?} -> {:?}>",
-                        func.func_arg().unwrap().ty(),
-                        func.func_result().unwrap().ty()
+                        func.func_arg().and_then(|arg| arg.ty()),
+                        func.func_result().and_then(|res| res.ty())
                     );
                 }
-                ty => println!("{:?}", ty),
+                Some(ty) => println!("{:?}", ty),
+                None => {}
             }
         } else if let Some(input) = input.strip_prefix(":p ") {
-            let parse: Parse = Parser::new(&input).parse_pattern();
+            let parse: Parse = Parser::new(input).parse_pattern();
             println!("{}", parse.debug_tree());
 
-            let pat: Pattern = AstNode::cast(parse.syntax()).unwrap();
-            let idents: Vec<String> = pat
-                .idents()
+            let idents: Vec<String> = Pattern::cast(parse.syntax())
+                .map_or(vec![], |pat| pat.idents())
                 .iter()
-                .map(|name| name.ident_token().unwrap().text().to_string())
+                .filter_map(|name| name.ident())
+                .map(|ident| ident.to_string())
                 .collect();
             println!("Idents in pattern: {:?}", idents);
+        } else if let Some(input) = input.strip_prefix(":e ") {
+            let parse: Parse = Parser::new(input).parse_expr();
+            println!("{}", parse.debug_tree());
+        } else {
+            let parse: Parse = Parser::new(&input).parse_prog();
+            println!("{}", parse.debug_tree());
         }
 
         input.clear();
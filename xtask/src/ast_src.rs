@@ -2,7 +2,6 @@
 
 #[derive(Default, Debug)]
 pub(crate) struct AstSrc {
-    pub(crate) tokens: Vec<String>,
     pub(crate) nodes: Vec<AstNodeSrc>,
     pub(crate) enums: Vec<AstEnumSrc>,
 }
@@ -38,3 +37,246 @@ pub(crate) struct AstEnumSrc {
     pub(crate) traits: Vec<String>,
     pub(crate) variants: Vec<String>,
 }
+
+/// The token kinds of the language. The kinds of nodes come from
+/// `grammar.ungram`.
+pub(crate) struct KindsSrc<'a> {
+    /// Whitespace and comments
+    pub(crate) trivia: &'a [LexedToken<'a>],
+    /// Named `<KEYWORD>_KW`, with a `*` spelled `_STAR`
+    pub(crate) keywords: &'a [&'a str],
+    pub(crate) literals: &'a [LexedToken<'a>],
+    /// Tokens that are neither literals nor punctuation, like identifiers
+    pub(crate) tokens: &'a [LexedToken<'a>],
+    pub(crate) punct: &'a [(&'a str, &'a str)],
+    /// Operators that overlap with the angle brackets of type arguments
+    /// (think `Array<Array<Nat>>`) are never produced by the lexer. The parser
+    /// glues them together from their single character parts instead.
+    pub(crate) glued_punct: &'a [(&'a str, &'a str)],
+}
+
+/// A token that is matched by the `logos` attributes in `lex`
+pub(crate) struct LexedToken<'a> {
+    pub(crate) doc: &'a str,
+    pub(crate) name: &'a str,
+    pub(crate) lex: &'a [&'a str],
+}
+
+pub(crate) const KINDS_SRC: KindsSrc = KindsSrc {
+    trivia: &[
+        LexedToken {
+            doc: "",
+            name: "SPACE",
+            lex: &[r#"#[regex(" +")]"#],
+        },
+        LexedToken {
+            doc: "",
+            name: "LINE_COMMENT",
+            lex: &[
+                r#"#[regex(r"//[^\r\n]*")]"#,
+                r#"#[regex(r"////[^\r\n]*", priority = 7)]"#,
+            ],
+        },
+        LexedToken {
+            doc: "",
+            name: "LINE_DOC_COMMENT",
+            lex: &[r#"#[regex(r"///[^\r\n]*")]"#],
+        },
+        LexedToken {
+            doc: "`/**/` and `/***` are ordinary comments, like in Rust",
+            name: "BLOCK_COMMENT",
+            lex: &[
+                r#"#[token("/*", block_comment)]"#,
+                r#"#[token("/**/", block_comment)]"#,
+                r#"#[token("/***", block_comment)]"#,
+            ],
+        },
+        LexedToken {
+            doc: "",
+            name: "BLOCK_DOC_COMMENT",
+            lex: &[r#"#[token("/**", block_comment)]"#],
+        },
+        LexedToken {
+            doc: "",
+            name: "TAB",
+            lex: &[r#"#[regex("\t+")]"#],
+        },
+        LexedToken {
+            doc: "",
+            name: "LINEFEED",
+            lex: &[r#"#[regex("\n|\r\n")]"#],
+        },
+    ],
+    keywords: &[
+        "func",
+        "let",
+        "var",
+        "switch",
+        "case",
+        "true",
+        "false",
+        "null",
+        "shared",
+        "query",
+        "async",
+        "async*",
+        "object",
+        "class",
+        "actor",
+        "module",
+        "prim",
+        "if",
+        "else",
+        "while",
+        "loop",
+        "for",
+        "in",
+        "return",
+        "await",
+        "await*",
+        "assert",
+        "debug",
+        "debug_show",
+        "ignore",
+        "and",
+        "or",
+        "not",
+        "label",
+        "break",
+        "continue",
+        "try",
+        "catch",
+        "throw",
+        "do",
+        "with",
+        "import",
+        "public",
+        "private",
+        "system",
+        "stable",
+        "flexible",
+        "type",
+    ],
+    literals: &[
+        LexedToken {
+            doc: "Misplaced underscores and missing digits are reported when the literal is \
+                  decoded, see `syntax::number`",
+            name: "NAT_LIT",
+            lex: &[
+                r#"#[regex("[0-9][0-9_]*")]"#,
+                r#"#[regex("0x[0-9a-fA-F_]*")]"#,
+            ],
+        },
+        LexedToken {
+            doc: "",
+            name: "FLOAT_LIT",
+            lex: &[
+                r#"#[regex(r"[0-9][0-9_]*\.[0-9_]*")]"#,
+                r#"#[regex(r"[0-9][0-9_]*(\.[0-9_]*)?[eE][+-]?[0-9][0-9_]*")]"#,
+                r#"#[regex(r"0x[0-9a-fA-F_]*\.[0-9a-fA-F_]*")]"#,
+                r#"#[regex(r"0x[0-9a-fA-F_]*(\.[0-9a-fA-F_]*)?[pP][+-]?[0-9][0-9_]*")]"#,
+            ],
+        },
+        LexedToken {
+            doc: "Escapes are validated when the literal is decoded, see `syntax::unescape`",
+            name: "TEXT_LIT",
+            lex: &[r##"#[regex(r#""(?:[^"\\]|\\(?:.|\n))*""#)]"##],
+        },
+        LexedToken {
+            doc: "",
+            name: "CHAR_LIT",
+            lex: &[r#"#[regex(r"'(?:[^'\\\n]|\\.)*'")]"#],
+        },
+    ],
+    tokens: &[
+        LexedToken {
+            doc: "Like moc, identifiers are ASCII only. Any other character is lexed as an \
+                  `ERROR` spanning the whole code point.",
+            name: "IDENT",
+            lex: &[r#"#[regex("[A-Za-z_][A-Za-z0-9_]*")]"#],
+        },
+        LexedToken {
+            doc: "Tuple projections like the `.1` in `t.1`",
+            name: "DOT_NUM",
+            lex: &[r#"#[regex(r"\.[0-9][0-9_]*")]"#],
+        },
+    ],
+    punct: &[
+        (".", "DOT"),
+        ("+", "PLUS"),
+        ("-", "MINUS"),
+        ("_", "UNDERSCORE"),
+        ("*", "STAR"),
+        ("/", "SLASH"),
+        ("=", "EQUALS"),
+        ("==", "DOUBLE_EQUALS"),
+        ("(", "L_PAREN"),
+        (")", "R_PAREN"),
+        ("[", "L_BRACKET"),
+        ("]", "R_BRACKET"),
+        ("{", "L_BRACE"),
+        ("}", "R_BRACE"),
+        ("<", "L_ANGLE"),
+        (">", "R_ANGLE"),
+        ("<:", "SUB"),
+        ("#", "HASH"),
+        ("?", "QUESTION"),
+        ("!", "BANG"),
+        (":", "COLON"),
+        (";", "SEMICOLON"),
+        (",", "COMMA"),
+        ("->", "ARROW"),
+        ("!=", "NOT_EQUALS"),
+        ("%", "PERCENT"),
+        ("**", "DOUBLE_STAR"),
+        ("&", "AMPERSAND"),
+        ("|", "PIPE"),
+        ("^", "CARET"),
+        ("|>", "PIPE_GREATER"),
+        (":=", "COLON_EQUALS"),
+        ("+=", "PLUS_EQUALS"),
+        ("-=", "MINUS_EQUALS"),
+        ("*=", "STAR_EQUALS"),
+        ("/=", "SLASH_EQUALS"),
+        ("%=", "PERCENT_EQUALS"),
+        ("**=", "DOUBLE_STAR_EQUALS"),
+        ("&=", "AMPERSAND_EQUALS"),
+        ("|=", "PIPE_EQUALS"),
+        ("^=", "CARET_EQUALS"),
+        ("#=", "HASH_EQUALS"),
+    ],
+    glued_punct: &[
+        ("<=", "LESS_EQUALS"),
+        (">=", "GREATER_EQUALS"),
+        ("<<", "SHL"),
+        (">>", "USHR"),
+        ("+>>", "SSHR"),
+        ("<<>", "ROTL"),
+        ("<>>", "ROTR"),
+        ("<<=", "SHL_EQUALS"),
+        (">>=", "USHR_EQUALS"),
+        ("+>>=", "SSHR_EQUALS"),
+        ("<<>=", "ROTL_EQUALS"),
+        ("<>>=", "ROTR_EQUALS"),
+    ],
+};
+
+/// Tokens that get a typed wrapper implementing `AstToken`, with the kinds
+/// they wrap
+pub(crate) const AST_TOKENS: &[(&str, &[&str])] = &[
+    ("Whitespace", &["SPACE", "TAB", "LINEFEED"]),
+    (
+        "Comment",
+        &[
+            "LINE_COMMENT",
+            "LINE_DOC_COMMENT",
+            "BLOCK_COMMENT",
+            "BLOCK_DOC_COMMENT",
+        ],
+    ),
+    ("Ident", &["IDENT"]),
+    ("NatLit", &["NAT_LIT"]),
+    ("FloatLit", &["FLOAT_LIT"]),
+    ("TextLit", &["TEXT_LIT"]),
+    ("CharLit", &["CHAR_LIT"]),
+];
@@ -1,20 +1,24 @@
-use proc_macro2::{Punct, Spacing};
+use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use std::{
-    collections::{BTreeSet, HashSet},
-    fs::File,
+    fs,
     io::Write,
+    path::Path,
+    process::{Command, Stdio},
     str::FromStr,
 };
 use ungrammar::{Grammar, Rule};
 
 use crate::{
-    ast_src::{AstEnumSrc, AstNodeSrc, AstSrc, Cardinality, Field},
-    // codegen::{reformat, update, Mode},
-    project_root,
-    Result,
+    ast_src::{
+        AstEnumSrc, AstNodeSrc, AstSrc, Cardinality, Field, KindsSrc, LexedToken, AST_TOKENS,
+        KINDS_SRC,
+    },
+    bail, project_root, Result,
 };
 
+const PREAMBLE: &str = "//! Generated by `cargo xtask codegen`, do not edit by hand.";
+
 /// Copied from the Rust compiler: https://github.com/rust-lang/rust/pull/62948/
 /// Replaces `\r\n` with `\n` in-place in `src`.
 ///
@@ -29,7 +33,7 @@ fn normalize_newlines(src: &mut String) {
     // directly, let's rather steal the contents of `src`. This makes the code
     // safe even if a panic occurs.
 
-    let mut buf = std::mem::replace(src, String::new()).into_bytes();
+    let mut buf = std::mem::take(src).into_bytes();
     let mut gap_len = 0;
     let mut tail = buf.as_mut_slice();
     loop {
@@ -70,8 +74,190 @@ fn normalize_newlines(src: &mut String) {
     }
 }
 
-pub fn run() -> Result<()> {
-    generate_syntax()
+/// Regenerates the syntax kinds and AST nodes. With `check` the files are
+/// left alone and we fail if they are out of date instead.
+pub fn run(check: bool) -> Result<()> {
+    generate_syntax(check)
+}
+
+/// Writes `contents` to `path` unless it's already there
+fn update(path: &Path, contents: &str, check: bool) -> Result<()> {
+    let mut old = fs::read_to_string(path).unwrap_or_default();
+    normalize_newlines(&mut old);
+    if old == contents {
+        return Ok(());
+    }
+    if check {
+        bail!(
+            "`{}` is out of date, run `cargo xtask codegen`",
+            path.display()
+        );
+    }
+    eprintln!("updating {}", path.display());
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+fn reformat(text: &str) -> Result<String> {
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2018"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    write!(rustfmt.stdin.take().unwrap(), "{}\n\n{}", PREAMBLE, text)?;
+    let output = rustfmt.wait_with_output()?;
+    if !output.status.success() {
+        bail!("rustfmt failed on the generated code");
+    }
+    let formatted = String::from_utf8(output.stdout)?;
+    Ok(doc_comments(&formatted))
+}
+
+/// `quote` turns doc comments into `#[doc = "..."]` attributes, so we turn
+/// them back
+fn doc_comments(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+        // `///` comments inside `quote!` come out as raw strings
+        let raw = trimmed
+            .strip_prefix("#[doc = r\"")
+            .and_then(|doc| doc.strip_suffix("\"]"));
+        let escaped = trimmed
+            .strip_prefix("#[doc = \"")
+            .and_then(|doc| doc.strip_suffix("\"]"));
+        match (raw, escaped) {
+            (Some(doc), _) => res.push_str(&format!("{}///{}", indent, doc)),
+            (None, Some(doc)) => {
+                res.push_str(&format!("{}///{}", indent, doc.replace("\\\"", "\"")))
+            }
+            (None, None) => res.push_str(line),
+        }
+        res.push('\n');
+    }
+    res
+}
+
+fn kw_name(keyword: &str) -> String {
+    format!("{}_KW", keyword.to_uppercase().replace('*', "_STAR"))
+}
+
+fn lexed_variant(token: &LexedToken) -> TokenStream {
+    let doc = if token.doc.is_empty() {
+        None
+    } else {
+        let doc = format!(" {}", token.doc);
+        Some(quote!(#[doc = #doc]))
+    };
+    let lex = token
+        .lex
+        .iter()
+        .map(|attr| TokenStream::from_str(attr).unwrap());
+    let name = format_ident!("{}", token.name);
+    quote! {
+        #doc
+        #(#lex)*
+        #name,
+    }
+}
+
+fn generate_syntax_kinds(kinds: &KindsSrc, ast: &AstSrc) -> Result<String> {
+    let trivia = kinds.trivia.iter().map(lexed_variant);
+    let keyword_names: Vec<_> = kinds
+        .keywords
+        .iter()
+        .map(|kw| format_ident!("{}", kw_name(kw)))
+        .collect();
+    let keyword_texts = kinds.keywords;
+    let literals: Vec<_> = kinds
+        .literals
+        .iter()
+        .map(|lit| format_ident!("{}", lit.name))
+        .collect();
+    let literal_variants = kinds.literals.iter().map(lexed_variant);
+    let tokens = kinds.tokens.iter().map(lexed_variant);
+    let punct_texts = kinds.punct.iter().map(|(text, _)| text);
+    let punct: Vec<_> = kinds
+        .punct
+        .iter()
+        .map(|(_, name)| format_ident!("{}", name))
+        .collect();
+    let glued_punct: Vec<_> = kinds
+        .glued_punct
+        .iter()
+        .map(|(_, name)| format_ident!("{}", name))
+        .collect();
+    let nodes = ast
+        .nodes
+        .iter()
+        .map(|node| format_ident!("{}", to_upper_snake_case(&node.name)));
+
+    let kinds_enum = quote! {
+        use super::block_comment;
+        use logos::Logos;
+        use num_derive::{FromPrimitive, ToPrimitive};
+
+        #[derive(Debug, Copy, Clone, PartialEq, Logos, FromPrimitive, ToPrimitive)]
+        #[repr(u16)]
+        #[allow(bad_style, missing_docs, unreachable_pub)]
+        pub enum SyntaxKind {
+            #(#trivia)*
+            #(#[token(#keyword_texts)] #keyword_names,)*
+            #(#tokens)*
+            #(#literal_variants)*
+            #(#[token(#punct_texts)] #punct,)*
+            #(#glued_punct,)*
+            #[error]
+            ERROR,
+            EOF,
+            #(#nodes,)*
+        }
+
+        impl SyntaxKind {
+            pub fn is_keyword(self) -> bool {
+                matches!(self, #(SyntaxKind::#keyword_names)|*)
+            }
+
+            pub fn is_punct(self) -> bool {
+                matches!(self, #(SyntaxKind::#punct)|* | #(SyntaxKind::#glued_punct)|*)
+            }
+
+            pub fn is_literal(self) -> bool {
+                matches!(self, #(SyntaxKind::#literals)|*)
+            }
+        }
+    };
+
+    // Written by hand, because rustfmt leaves macros alone
+    let mut macro_arms = vec![];
+    for (text, name) in kinds.punct.iter().chain(kinds.glued_punct) {
+        let text = if "()[]{}".contains(text) {
+            format!("'{}'", text)
+        } else {
+            text.to_string()
+        };
+        macro_arms.push((text, name.to_string()));
+    }
+    for kw in kinds.keywords {
+        macro_arms.push((kw.to_string(), kw_name(kw)));
+    }
+    for token in kinds.literals.iter().chain(kinds.tokens) {
+        macro_arms.push((token.name.to_lowercase(), token.name.to_string()));
+    }
+    let mut t_macro = String::from("#[macro_export]\nmacro_rules! T {\n");
+    for (text, name) in macro_arms {
+        t_macro.push_str(&format!(
+            "    [{}] => {{ $crate::lexer::SyntaxKind::{} }};\n",
+            text, name
+        ));
+    }
+    t_macro.push_str("}\n");
+
+    reformat(&format!("{}\n{}", kinds_enum, t_macro))
 }
 
 fn generate_nodes(grammar: &AstSrc) -> Result<String> {
@@ -81,10 +267,8 @@ fn generate_nodes(grammar: &AstSrc) -> Result<String> {
         .map(|node| {
             let name = format_ident!("{}", node.name);
             let kind = format_ident!("{}", to_upper_snake_case(&node.name));
+            let doc = &node.doc;
 
-            // TODO: We're not using these yet, but eventually we can
-            // use these to increase ergonomics around nodes that hold
-            // names
             let traits = node.traits.iter().map(|trait_name| {
                 let trait_name = format_ident!("{}", trait_name);
                 quote!(impl ast::#trait_name for #name {})
@@ -101,10 +285,19 @@ fn generate_nodes(grammar: &AstSrc) -> Result<String> {
                         }
                     }
                 } else if let Some(token_kind) = field.token_kind() {
+                    // Tokens with a typed wrapper get an accessor for it too
+                    let typed = field.ast_token().map(|(name, ast_token)| {
+                        quote! {
+                            pub fn #name(&self) -> Option<#ast_token> {
+                                support::ast_token(&self.syntax)
+                            }
+                        }
+                    });
                     quote! {
                         pub fn #method_name(&self) -> Option<#ty> {
                             support::token(&self.syntax, #token_kind)
                         }
+                        #typed
                     }
                 } else {
                     quote! {
@@ -116,6 +309,7 @@ fn generate_nodes(grammar: &AstSrc) -> Result<String> {
             });
             (
                 quote! {
+                    #(#[doc = #doc])*
                     #[derive(Debug, Clone, PartialEq, Eq, Hash)]
                     pub struct #name {
                         pub(crate) syntax: SyntaxNode,
@@ -152,6 +346,7 @@ fn generate_nodes(grammar: &AstSrc) -> Result<String> {
                 .map(|var| format_ident!("{}", var))
                 .collect();
             let name = format_ident!("{}", en.name);
+            let doc = &en.doc;
             let kinds: Vec<_> = variants
                 .iter()
                 .map(|name| format_ident!("{}", to_upper_snake_case(&name.to_string())))
@@ -167,10 +362,7 @@ fn generate_nodes(grammar: &AstSrc) -> Result<String> {
                 quote! {
                     impl AstNode for #name {
                         fn can_cast(kind: SyntaxKind) -> bool {
-                            match kind {
-                                #(#kinds)|* => true,
-                                _ => false,
-                            }
+                            matches!(kind, #(#kinds)|*)
                         }
                         fn cast(syntax: SyntaxNode) -> Option<Self> {
                             let res = match syntax.kind() {
@@ -194,6 +386,7 @@ fn generate_nodes(grammar: &AstSrc) -> Result<String> {
 
             (
                 quote! {
+                    #(#[doc = #doc])*
                     #[derive(Debug, Clone, PartialEq, Eq, Hash)]
                     pub enum #name {
                         #(#variants(#variants),)*
@@ -231,10 +424,29 @@ fn generate_nodes(grammar: &AstSrc) -> Result<String> {
             }
         });
 
+    let uses_traits = grammar
+        .nodes
+        .iter()
+        .map(|it| &it.traits)
+        .chain(grammar.enums.iter().map(|it| &it.traits))
+        .any(|traits| !traits.is_empty());
+    let ast_mod = if uses_traits { quote!(self,) } else { quote!() };
+
+    let mut ast_tokens: Vec<_> = grammar
+        .nodes
+        .iter()
+        .flat_map(|node| &node.fields)
+        .filter_map(|field| field.ast_token())
+        .map(|(_, ast_token)| ast_token)
+        .collect();
+    ast_tokens.sort();
+    ast_tokens.dedup();
+
     let ast = quote! {
         use super::{
             SyntaxNode, SyntaxToken, SyntaxKind::{self, *},
-            ast::{self, AstNode, AstChildren, support},
+            ast::{#ast_mod AstNode, AstChildren, support},
+            tokens::{#(#ast_tokens),*},
         };
         use crate::T;
 
@@ -247,32 +459,129 @@ fn generate_nodes(grammar: &AstSrc) -> Result<String> {
 
     let ast = ast.to_string().replace("T ! [", "T![");
 
-    Ok(ast)
+    reformat(&ast)
 }
 
-fn generate_syntax() -> Result<()> {
-    let mut grammar_str = include_str!("../../crates/mokoto/src/syntax/grammar.ungram").to_string();
-    normalize_newlines(&mut grammar_str);
-    let grammar = Grammar::from_str(&grammar_str).unwrap();
-    println!("{:#?}", grammar);
+fn generate_tokens(tokens: &[(&str, &[&str])]) -> Result<String> {
+    let tokens = tokens.iter().map(|(name, kinds)| {
+        let name = format_ident!("{}", name);
+        let kinds = kinds.iter().map(|kind| format_ident!("{}", kind));
+        quote! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+            pub struct #name {
+                pub(crate) syntax: SyntaxToken,
+            }
 
+            impl std::fmt::Display for #name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    std::fmt::Display::fmt(&self.syntax, f)
+                }
+            }
+
+            impl AstToken for #name {
+                fn can_cast(kind: SyntaxKind) -> bool {
+                    matches!(kind, #(#kinds)|*)
+                }
+                fn cast(syntax: SyntaxToken) -> Option<Self> {
+                    if Self::can_cast(syntax.kind()) { Some(Self { syntax }) } else { None }
+                }
+                fn syntax(&self) -> &SyntaxToken { &self.syntax }
+            }
+        }
+    });
+    let tokens = quote! {
+        use super::{ast::AstToken, SyntaxKind::{self, *}, SyntaxToken};
+
+        #(#tokens)*
+    };
+    reformat(&tokens.to_string())
+}
+
+fn generate_syntax(check: bool) -> Result<()> {
+    let src = project_root().join("crates").join("mokoto").join("src");
+    let mut grammar_str = fs::read_to_string(src.join("syntax").join("grammar.ungram"))?;
+    normalize_newlines(&mut grammar_str);
+    let grammar = Grammar::from_str(&grammar_str)?;
     let ast = lower(&grammar);
 
-    println!("{:#?}", ast);
+    let kinds = generate_syntax_kinds(&KINDS_SRC, &ast)?;
+    update(&src.join("lexer").join("syntax_kind.rs"), &kinds, check)?;
+
+    let tokens = generate_tokens(AST_TOKENS)?;
+    update(&src.join("syntax").join("tokens.rs"), &tokens, check)?;
 
-    let src = generate_nodes(&ast)?;
+    let nodes = generate_nodes(&ast)?;
+    update(&src.join("syntax").join("nodes.rs"), &nodes, check)?;
 
-    let nodes_path = project_root()
-        .join("crates")
-        .join("mokoto")
-        .join("src")
-        .join("syntax")
-        .join("nodes.rs");
-    let mut file: File = File::create(nodes_path)?;
-    write!(file, "{}", src)?;
+    let tests = project_root().join("crates").join("mokoto").join("tests");
+    let accessors = generate_accessor_calls(&ast)?;
+    update(&tests.join("accessors").join("mod.rs"), &accessors, check)?;
     Ok(())
 }
 
+/// A test helper that calls every generated accessor on a node, so we can
+/// check they don't panic on trees with errors
+fn generate_accessor_calls(grammar: &AstSrc) -> Result<String> {
+    let trait_calls = |traits: &[String]| {
+        let calls = traits.iter().flat_map(|trait_name| {
+            let methods = TRAITS
+                .iter()
+                .find(|(name, _)| name == trait_name)
+                .map_or(&["doc_comments"][..], |(_, methods)| methods);
+            let trait_name = format_ident!("{}", trait_name);
+            methods.iter().map(move |method| {
+                let method = format_ident!("{}", method);
+                quote!(let _ = ast::#trait_name::#method(&it);)
+            })
+        });
+        quote!(#(#calls)*)
+    };
+    let nodes = grammar.nodes.iter().map(|node| {
+        let name = format_ident!("{}", node.name);
+        if node.fields.is_empty() && node.traits.is_empty() {
+            return quote!();
+        }
+        let methods = node.fields.iter().map(|field| {
+            let method_name = field.method_name();
+            let typed = field
+                .ast_token()
+                .map(|(name, _)| quote!(let _ = it.#name();));
+            if field.is_many() {
+                quote!(let _ = it.#method_name().count();)
+            } else {
+                quote!(let _ = it.#method_name(); #typed)
+            }
+        });
+        let traits = trait_calls(&node.traits);
+        quote! {
+            if let Some(it) = #name::cast(node.clone()) {
+                #(#methods)*
+                #traits
+            }
+        }
+    });
+    let enums = grammar.enums.iter().map(|en| {
+        let name = format_ident!("{}", en.name);
+        let traits = trait_calls(&en.traits);
+        quote! {
+            if let Some(it) = #name::cast(node.clone()) {
+                let _ = it.syntax();
+                #traits
+            }
+        }
+    });
+    let calls = quote! {
+        use mokoto::syntax::{ast::{self, AstNode}, nodes::*, SyntaxNode};
+
+        /// Calls every generated accessor of `node`, whatever kind it is
+        pub fn call_all(node: &SyntaxNode) {
+            #(#nodes)*
+            #(#enums)*
+        }
+    };
+    reformat(&calls.to_string())
+}
+
 fn lower(grammar: &Grammar) -> AstSrc {
     let mut res = AstSrc::default();
     let nodes = grammar.iter().collect::<Vec<_>>();
@@ -301,9 +610,80 @@ fn lower(grammar: &Grammar) -> AstSrc {
             }
         }
     }
+    deduplicate_fields(&mut res);
+    extract_struct_traits(&mut res);
+    extract_enum_traits(&mut res);
     res
 }
 
+/// Accessors shared between many nodes live in traits in `ast`, so tooling
+/// can work across declarations uniformly. The trait is implemented for every
+/// node that has all of its fields, and replaces the generated methods.
+const TRAITS: &[(&str, &[&str])] = &[
+    ("HasName", &["name"]),
+    ("HasTypeParams", &["type_params"]),
+    ("HasTypeAnnotation", &["type_annotation"]),
+    ("HasVisibility", &["visibility"]),
+];
+
+fn extract_struct_traits(ast: &mut AstSrc) {
+    for node in &mut ast.nodes {
+        for (name, methods) in TRAITS {
+            extract_struct_trait(node, name, methods);
+        }
+        // Declarations and the fields wrapping them can be documented
+        if node.name.ends_with("Decl") || node.traits.iter().any(|t| t == "HasVisibility") {
+            node.traits.push("HasDocComments".to_string());
+        }
+    }
+}
+
+fn extract_struct_trait(node: &mut AstNodeSrc, trait_name: &str, methods: &[&str]) {
+    let has_all = methods
+        .iter()
+        .all(|&method| node.fields.iter().any(|f| f.method_name() == method));
+    if has_all {
+        node.fields
+            .retain(|f| !methods.iter().any(|&method| f.method_name() == method));
+        node.traits.push(trait_name.to_string());
+    }
+}
+
+/// Enums get the traits all of their variants share
+fn extract_enum_traits(ast: &mut AstSrc) {
+    for i in 0..ast.enums.len() {
+        let variants = &ast.enums[i].variants;
+        let mut traits = match ast.nodes.iter().find(|n| Some(&n.name) == variants.first()) {
+            Some(node) => node.traits.clone(),
+            None => continue,
+        };
+        for variant in variants {
+            match ast.nodes.iter().find(|n| &n.name == variant) {
+                Some(node) => traits.retain(|t| node.traits.contains(t)),
+                None => traits.clear(),
+            }
+        }
+        ast.enums[i].traits = traits;
+    }
+}
+
+/// Rules that mention a child more than once, like `'ident' ('.' 'ident')*`,
+/// only get one accessor for it
+fn deduplicate_fields(ast: &mut AstSrc) {
+    for node in &mut ast.nodes {
+        let mut i = 0;
+        'outer: while i < node.fields.len() {
+            for j in 0..i {
+                if node.fields[i] == node.fields[j] {
+                    node.fields.remove(i);
+                    continue 'outer;
+                }
+            }
+            i += 1;
+        }
+    }
+}
+
 fn lower_enum(grammar: &Grammar, rule: &Rule) -> Option<Vec<String>> {
     let alternatives = match rule {
         Rule::Alt(it) => it,
@@ -362,7 +742,8 @@ fn lower_rule(acc: &mut Vec<Field>, grammar: &Grammar, label: Option<&String>, r
                 acc.push(field);
                 return;
             }
-            todo!("{:?}", rule)
+            // Repeated tokens like the `('.' 'ident')*` in a path
+            lower_rule(acc, grammar, label, inner)
         }
         Rule::Labeled { label: l, rule } => {
             assert!(label.is_none());
@@ -380,6 +761,9 @@ fn lower_rule(acc: &mut Vec<Field>, grammar: &Grammar, label: Option<&String>, r
                     | "value"
                     | "trait"
                     | "self_ty"
+                    | "condition"
+                    | "body"
+                    | "arg"
             );
             if manually_implemented {
                 return;
@@ -399,6 +783,9 @@ fn lower_rule(acc: &mut Vec<Field>, grammar: &Grammar, label: Option<&String>, r
 // (T (',' T)*)
 // or
 // (T (';' T)*)
+// or
+// (T ('and' T)*)
+// with an optional trailing separator
 fn lower_comma_list(
     acc: &mut Vec<Field>,
     grammar: &Grammar,
@@ -409,21 +796,29 @@ fn lower_comma_list(
         Rule::Seq(it) => it,
         _ => return false,
     };
-    let (node, repeat) = match rule.as_slice() {
-        [Rule::Node(node), Rule::Rep(repeat)] => (node, repeat),
+    let (node, repeat, trailing) = match rule.as_slice() {
+        [Rule::Node(node), Rule::Rep(repeat)] => (node, repeat, None),
+        [Rule::Node(node), Rule::Rep(repeat), Rule::Opt(trailing)] => {
+            (node, repeat, Some(trailing))
+        }
         _ => return false,
     };
     let repeat = match &**repeat {
         Rule::Seq(it) => it,
         _ => return false,
     };
-    match repeat.as_slice() {
+    let comma = match repeat.as_slice() {
         [Rule::Token(comma), Rule::Node(n)]
-            if n == node && (grammar[*comma].name == "," || grammar[*comma].name == ";") =>
+            if n == node && matches!(grammar[*comma].name.as_str(), "," | ";" | "and") =>
         {
-            ()
+            comma
         }
         _ => return false,
+    };
+    match trailing.map(|it| &**it) {
+        None => {}
+        Some(Rule::Token(t)) if t == comma => {}
+        _ => return false,
     }
     let ty = grammar[*node].name.clone();
     let name = label
@@ -472,7 +867,13 @@ fn pluralize(s: &str) -> String {
 
 impl Field {
     fn is_many(&self) -> bool {
-        matches!(self, Field::Node { cardinality: Cardinality::Many, .. })
+        matches!(
+            self,
+            Field::Node {
+                cardinality: Cardinality::Many,
+                ..
+            }
+        )
     }
     fn token_kind(&self) -> Option<proc_macro2::TokenStream> {
         match self {
@@ -483,6 +884,20 @@ impl Field {
             _ => None,
         }
     }
+    /// The name of the typed accessor and the `AstToken` wrapper for tokens
+    /// that have one, like `ident` and `Ident` for `'ident'`
+    fn ast_token(&self) -> Option<(proc_macro2::Ident, proc_macro2::Ident)> {
+        let token = match self {
+            Field::Token(token) => token,
+            Field::Node { .. } => return None,
+        };
+        let kind = token.to_uppercase();
+        let (name, _) = AST_TOKENS
+            .iter()
+            .find(|(_, kinds)| kinds == &[kind.as_str()])?;
+        Some((format_ident!("{}", token), format_ident!("{}", name)))
+    }
+
     fn ty(&self) -> proc_macro2::Ident {
         match self {
             Field::Token(_) => format_ident!("SyntaxToken"),
@@ -516,6 +931,8 @@ impl Field {
                     "?" => "question_mark",
                     "," => "comma",
                     "<:" => "sub",
+                    "async*" => "async_star",
+                    "await*" => "await_star",
                     _ => name,
                 };
                 format_ident!("{}_token", name)
@@ -530,3 +947,11 @@ impl Field {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn generated_files_are_up_to_date() {
+        super::run(true).unwrap()
+    }
+}
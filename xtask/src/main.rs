@@ -5,8 +5,6 @@
 //! This binary is integrated into the `cargo` command line by using an alias in
 //! `.cargo/config`.
 
-use std::env;
-
 use pico_args::Arguments;
 use xshell::pushd;
 use xtask::{codegen, project_root, Result};
@@ -19,8 +17,9 @@ fn main() -> Result<()> {
 
     match subcommand.as_str() {
         "codegen" => {
+            let check = args.contains("--check");
             args.finish()?;
-            codegen::run()
+            codegen::run(check)
         }
         _ => {
             eprintln!(
@@ -31,7 +30,7 @@ USAGE:
     cargo xtask <SUBCOMMAND>
 SUBCOMMANDS:
     format
-    codegen"
+    codegen [--check]"
             );
             Ok(())
         }